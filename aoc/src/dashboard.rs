@@ -0,0 +1,225 @@
+use crate::input_name;
+use anyhow::{anyhow, bail, Error};
+use aoc::Day;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use rayon::prelude::*;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tools::{Ledger, ParseError, Parsed, Part};
+
+const TICK: Duration = Duration::from_millis(100);
+
+// a day's input, read and parsed once by whichever of its parts' jobs gets there first
+struct DayInput {
+    day: &'static Day,
+    loaded: OnceLock<Result<Loaded, Error>>,
+}
+
+struct Loaded {
+    input_name: String,
+    parsed: Box<dyn Parsed>,
+    ledger: Ledger,
+    parse_time: Duration,
+}
+
+impl DayInput {
+    fn new(day: &'static Day) -> Self {
+        Self {
+            day,
+            loaded: OnceLock::new(),
+        }
+    }
+
+    // waits if another job is already loading it
+    fn get(&self) -> Result<&Loaded, Error> {
+        self.loaded
+            .get_or_init(|| self.load())
+            .as_ref()
+            .map_err(|err| anyhow!("{err:#}"))
+    }
+
+    fn load(&self) -> Result<Loaded, Error> {
+        let input = self.day.default_input();
+        let text = std::fs::read_to_string(&input)
+            .map_err(|err| anyhow!("failed to read {}: {err}", input.display()))?;
+        let ledger = Ledger::load(&self.day.ledger_path())?;
+        let input_name = input_name(&input)?;
+
+        let start = Instant::now();
+        let parsed = (self.day.parse)(&text, &ledger.settings(&input_name))
+            .map_err(|err| ParseError::in_source(err, &input.display().to_string()))?;
+
+        Ok(Loaded {
+            input_name,
+            parsed,
+            ledger,
+            parse_time: start.elapsed(),
+        })
+    }
+
+    fn parse_time(&self) -> Option<Duration> {
+        Some(self.loaded.get()?.as_ref().ok()?.parse_time)
+    }
+}
+
+// one part of one day, each run as its own job on the thread pool
+struct Job<'a> {
+    input: &'a DayInput,
+    part: u32,
+}
+
+impl Job<'_> {
+    fn label(&self) -> String {
+        format!("day {:02} part {}", self.input.day.number, self.part)
+    }
+
+    // the answer, what the ledger says about it, and how long solving took
+    fn run(&self) -> Result<(String, String, Duration), Error> {
+        let loaded = self.input.get()?;
+
+        let start = Instant::now();
+        let answer = loaded.parsed.solve_part(self.part)?;
+        let elapsed = start.elapsed();
+
+        let check = loaded
+            .ledger
+            .check(&loaded.input_name, self.part, &answer)
+            .to_string();
+        Ok((answer, check, elapsed))
+    }
+}
+
+struct Outcome {
+    label: String,
+    result: Result<(String, String), Error>,
+    elapsed: Duration,
+}
+
+// every part of every day at once, with a spinner per running part
+// and a line per finished one, then a table of the answers
+pub fn run_all(days: &'static [Day], part: Part) -> Result<(), Error> {
+    let inputs: Vec<DayInput> = days.iter().map(DayInput::new).collect();
+    let jobs: Vec<Job> = inputs
+        .iter()
+        .flat_map(|input| {
+            [(1, part.includes_one()), (2, part.includes_two())]
+                .into_iter()
+                .filter(|(_part, included)| *included)
+                .map(move |(part, _included)| Job { input, part })
+        })
+        .collect();
+
+    let multi = MultiProgress::new();
+    let overall = multi.add(ProgressBar::new(jobs.len() as u64));
+    overall.set_style(ProgressStyle::with_template(
+        "{bar:40} {pos}/{len} parts {elapsed}",
+    )?);
+    overall.enable_steady_tick(TICK);
+    let spinner_style = ProgressStyle::with_template("{spinner} {prefix} running {elapsed}")?;
+
+    let start = Instant::now();
+    let outcomes: Vec<Outcome> = jobs
+        .par_iter()
+        .map(|job| {
+            let label = job.label();
+            let spinner = multi.add(
+                ProgressBar::new_spinner()
+                    .with_style(spinner_style.clone())
+                    .with_prefix(label.clone()),
+            );
+            spinner.enable_steady_tick(TICK);
+
+            // failures are timed from the start, loading and all
+            let job_start = Instant::now();
+            let (result, elapsed) = match job.run() {
+                Ok((answer, check, elapsed)) => (Ok((answer, check)), elapsed),
+                Err(err) => (Err(err), job_start.elapsed()),
+            };
+
+            spinner.finish_and_clear();
+            multi.remove(&spinner);
+
+            let status = match &result {
+                Ok(_) => format!("done in {elapsed:.2?}"),
+                Err(_) => "FAILED".to_owned(),
+            };
+            // only fails if the terminal went away, and then nobody's watching
+            let _ = multi.println(format!("{label} {status}"));
+            overall.inc(1);
+
+            Outcome {
+                label,
+                result,
+                elapsed,
+            }
+        })
+        .collect();
+
+    overall.finish_and_clear();
+    let parse_time = inputs.iter().filter_map(DayInput::parse_time).sum();
+    print_summary(&outcomes, start.elapsed(), parse_time);
+
+    let num_failed = outcomes
+        .iter()
+        .filter(|outcome| outcome.result.is_err())
+        .count();
+    if num_failed > 0 {
+        bail!("{num_failed} of {} parts failed", outcomes.len());
+    }
+
+    Ok(())
+}
+
+// part times are for solving alone, each day's input is parsed once for both parts
+fn print_summary(outcomes: &[Outcome], wall_time: Duration, parse_time: Duration) {
+    let label_width = outcomes
+        .iter()
+        .map(|outcome| outcome.label.len())
+        .max()
+        .unwrap_or_default();
+    let answer_width = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.result.as_ref().ok())
+        .map(|(answer, _check)| answer.len())
+        .fold("answer".len(), usize::max);
+
+    println!();
+    println!(
+        "{:<label_width$}  {:<answer_width$}  {:>10}  check",
+        "", "answer", "time"
+    );
+
+    for outcome in outcomes {
+        let (answer, check) = match &outcome.result {
+            Ok((answer, check)) => (answer.as_str(), check.as_str()),
+            Err(_) => ("-", "failed"),
+        };
+        let elapsed = format!("{:.2?}", outcome.elapsed);
+
+        println!(
+            "{:<label_width$}  {answer:<answer_width$}  {elapsed:>10}  {check}",
+            outcome.label
+        );
+    }
+
+    let failures: Vec<_> = outcomes
+        .iter()
+        .filter_map(|outcome| Some((&outcome.label, outcome.result.as_ref().err()?)))
+        .collect();
+
+    if !failures.is_empty() {
+        println!();
+    }
+    for (label, err) in &failures {
+        println!("{label} failed: {err:#}");
+    }
+
+    let total_time: Duration = outcomes.iter().map(|outcome| outcome.elapsed).sum();
+    println!();
+    println!(
+        "{} parts, {} failed, {wall_time:.2?} wall time, \
+         {total_time:.2?} solving and {parse_time:.2?} parsing summed",
+        outcomes.len(),
+        failures.len()
+    );
+}
@@ -0,0 +1,75 @@
+use anyhow::Error;
+use std::path::PathBuf;
+use tools::{Answers, Parsed, Part, Recording, Settings, Solution, Timings};
+
+// a day's parsed input, with its concrete type erased
+type ParsedInput = Box<dyn Parsed>;
+
+pub struct Day {
+    pub number: u32,
+    pub solve: fn(&str, &Settings, Part) -> Result<Answers, Error>,
+    pub parse: fn(&str, &Settings) -> Result<ParsedInput, Error>,
+    pub bench: fn(&str, &Settings, Part, usize) -> Result<Timings, Error>,
+    pub record: fn(&str, &Settings, u32) -> Result<Recording, Error>,
+}
+
+impl Day {
+    const fn new<S: Solution + 'static>(number: u32) -> Self
+    where
+        S::Input: Send + Sync,
+    {
+        Self {
+            number,
+            solve: tools::solve::<S>,
+            parse: tools::parse_input::<S>,
+            bench: tools::bench::<S>,
+            record: tools::record::<S>,
+        }
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day{:02}", self.number))
+    }
+
+    // the full puzzle input, as opposed to the examples
+    pub fn default_input(&self) -> PathBuf {
+        self.dir().join("input02.txt")
+    }
+
+    pub fn ledger_path(&self) -> PathBuf {
+        self.dir().join("answers.txt")
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13),
+    Day::new::<day14::Day14>(14),
+    Day::new::<day15::Day15>(15),
+    Day::new::<day16::Day16>(16),
+    Day::new::<day17::Day17>(17),
+    Day::new::<day18::Day18>(18),
+    Day::new::<day19::Day19>(19),
+    Day::new::<day20::Day20>(20),
+    Day::new::<day22::Day22>(22),
+    Day::new::<day23::Day23>(23),
+    Day::new::<day24::Day24>(24),
+    Day::new::<day25::Day25>(25),
+];
+
+pub fn find_day(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
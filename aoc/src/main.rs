@@ -0,0 +1,271 @@
+mod dashboard;
+
+use anyhow::{anyhow, bail, Error};
+use aoc::{find_day, Day, DAYS};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr as _;
+use std::time::Duration;
+use tools::{Entry, Ledger, ParseError, Part, Record, Verdict};
+
+const USAGE: &str = "usage:
+    aoc <day|all> [input] [--part 1|2|both]
+    aoc bench <day|all> [input] [--part 1|2|both] [--runs N] [--out FILE] [--baseline FILE]
+    aoc replay <day> [input] [--part 1|2] [--export FILE]
+    aoc record <day> <input> <part> <correct|wrong|too-high|too-low|pinned> <answer>";
+
+const REPLAY_HELP: &str = "enter: next frame, b: back, j <frame>: jump, q: quit";
+
+struct Args {
+    positional: Vec<String>,
+    part: Part,
+    runs: usize,
+    // where bench results are written
+    out: PathBuf,
+    // earlier bench results to compare against
+    baseline: Option<PathBuf>,
+    // where to write replay frames instead of stepping through them
+    export: Option<PathBuf>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, Error> {
+    let mut positional = Vec::new();
+    let mut part = Part::Both;
+    let mut runs = 10;
+    let mut out = PathBuf::from("bench.txt");
+    let mut baseline = None;
+    let mut export = None;
+
+    while let Some(arg) = args.next() {
+        let mut val = || args.next().ok_or_else(|| anyhow!("{USAGE}"));
+
+        match arg.as_str() {
+            "--part" => part = Part::from_str(&val()?)?,
+            "--runs" => runs = usize::from_str(&val()?)?,
+            "--out" => out = PathBuf::from(val()?),
+            "--baseline" => baseline = Some(PathBuf::from(val()?)),
+            "--export" => export = Some(PathBuf::from(val()?)),
+            _ => positional.push(arg),
+        }
+    }
+
+    Ok(Args {
+        positional,
+        part,
+        runs,
+        out,
+        baseline,
+        export,
+    })
+}
+
+fn parse_day(which: &str) -> Result<&'static Day, Error> {
+    let number = u32::from_str(which).map_err(|_| anyhow!("{USAGE}"))?;
+    find_day(number).ok_or_else(|| anyhow!("no solution for day {number}"))
+}
+
+// ledger entries are keyed by the input's file name
+fn input_name(input: &Path) -> Result<String, Error> {
+    let name = input
+        .file_name()
+        .ok_or_else(|| anyhow!("bad input path {}", input.display()))?;
+    Ok(name.to_string_lossy().into_owned())
+}
+
+fn run_day(day: &Day, input: PathBuf, part: Part) -> Result<(), Error> {
+    let text = std::fs::read_to_string(&input)
+        .map_err(|err| anyhow!("failed to read {}: {err}", input.display()))?;
+    let ledger = Ledger::load(&day.ledger_path())?;
+    let name = input_name(&input)?;
+
+    println!("day {:02} ({})", day.number, input.display());
+    let answers = (day.solve)(&text, &ledger.settings(&name), part)
+        .map_err(|err| ParseError::in_source(err, &input.display().to_string()))?;
+    answers.print_checked(&ledger, &name);
+    Ok(())
+}
+
+fn bench_day(
+    day: &Day,
+    input: PathBuf,
+    args: &Args,
+    baseline: &[Record],
+) -> Result<Vec<Record>, Error> {
+    let text = std::fs::read_to_string(&input)
+        .map_err(|err| anyhow!("failed to read {}: {err}", input.display()))?;
+    let name = input_name(&input)?;
+    let settings = Ledger::load(&day.ledger_path())?.settings(&name);
+
+    println!("day {:02} ({})", day.number, input.display());
+    let timings = (day.bench)(&text, &settings, args.part, args.runs)
+        .map_err(|err| ParseError::in_source(err, &input.display().to_string()))?;
+    let mut records = Vec::new();
+
+    for (stage, samples) in timings.stages() {
+        let samples = match samples {
+            Ok(samples) => samples,
+            Err(err) => {
+                println!("{stage} failed: {err:#}");
+                continue;
+            }
+        };
+
+        let record = Record::new(day.number, &name, stage, samples);
+        let change = baseline
+            .iter()
+            .find(|old| old.day == record.day && old.input == record.input && old.stage == stage)
+            .map(|old| {
+                format!(
+                    ", {} vs baseline",
+                    percent_change(old.median, record.median)
+                )
+            })
+            .unwrap_or_default();
+
+        println!(
+            "{stage} median {:.2?} (min {:.2?}, max {:.2?}, {} runs{change})",
+            record.median, record.min, record.max, record.runs
+        );
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+fn percent_change(old: Duration, new: Duration) -> String {
+    let change = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+    format!("{change:+.1}%")
+}
+
+fn bench(days: &[&Day], input: Option<&str>, args: &Args) -> Result<(), Error> {
+    let baseline = match &args.baseline {
+        Some(path) => Record::parse_all(&std::fs::read_to_string(path)?)
+            .map_err(|err| anyhow!("{}: {err:#}", path.display()))?,
+        None => Vec::new(),
+    };
+
+    let mut records = Vec::new();
+
+    for day in days {
+        let input = input.map_or_else(|| day.default_input(), PathBuf::from);
+        match bench_day(day, input, args, &baseline) {
+            Ok(day_records) => records.extend(day_records),
+            Err(err) => println!("day {:02} failed: {err:#}", day.number),
+        }
+    }
+
+    let mut file = std::fs::File::create(&args.out)?;
+    writeln!(
+        file,
+        "# <day> <input> <stage> <runs> <median ns> <min ns> <max ns>"
+    )?;
+    for record in &records {
+        writeln!(file, "{record}")?;
+    }
+
+    println!("wrote {}", args.out.display());
+    Ok(())
+}
+
+fn replay(day: &Day, input: PathBuf, args: &Args) -> Result<(), Error> {
+    let text = std::fs::read_to_string(&input)
+        .map_err(|err| anyhow!("failed to read {}: {err}", input.display()))?;
+    let part = match args.part {
+        Part::One | Part::Both => 1,
+        Part::Two => 2,
+    };
+
+    let settings = Ledger::load(&day.ledger_path())?.settings(&input_name(&input)?);
+
+    let recording = (day.record)(&text, &settings, part)
+        .map_err(|err| ParseError::in_source(err, &input.display().to_string()))?;
+
+    if let Some(path) = &args.export {
+        recording.export(&mut std::io::BufWriter::new(std::fs::File::create(path)?))?;
+        println!(
+            "wrote {} frames to {}",
+            recording.num_frames(),
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let mut replay = recording.replay();
+    let mut lines = std::io::stdin().lines();
+    println!("{REPLAY_HELP}");
+
+    loop {
+        println!("{}", replay.header());
+        println!("{}", replay.render().color(true));
+
+        let Some(ln) = lines.next() else {
+            break;
+        };
+
+        let ln = ln?;
+        match ln.trim().split_once(' ').unwrap_or((ln.trim(), "")) {
+            ("" | "n", _) => {
+                replay.forward();
+            }
+            ("b", _) => {
+                replay.back();
+            }
+            ("j", frame) => match usize::from_str(frame) {
+                Ok(frame) => replay.jump(frame),
+                Err(_) => println!("{REPLAY_HELP}"),
+            },
+            ("q", _) => break,
+            _ => println!("{REPLAY_HELP}"),
+        }
+    }
+
+    Ok(())
+}
+
+fn record(day: &Day, input: &str, part: &str, verdict: &str, answer: &str) -> Result<(), Error> {
+    let entry = Entry {
+        input: input_name(Path::new(input))?,
+        part: u32::from_str(part)?,
+        verdict: Verdict::from_str(verdict)?,
+        answer: answer.to_owned(),
+    };
+
+    // round trip to validate the entry before writing it
+    let entry = Entry::from_str(&entry.to_string())?;
+    Ledger::record(&day.ledger_path(), &entry)?;
+    println!("recorded {entry}");
+    Ok(())
+}
+
+fn main() -> Result<(), Error> {
+    let args = parse_args(std::env::args().skip(1))?;
+    let positional: Vec<&str> = args.positional.iter().map(String::as_str).collect();
+
+    match positional.as_slice() {
+        ["all"] => dashboard::run_all(DAYS, args.part)?,
+        ["bench", "all"] => {
+            let days: Vec<&Day> = DAYS.iter().collect();
+            bench(&days, None, &args)?;
+        }
+        ["bench", which] => bench(&[parse_day(which)?], None, &args)?,
+        ["bench", which, input] => bench(&[parse_day(which)?], Some(input), &args)?,
+        ["replay", which] => {
+            let day = parse_day(which)?;
+            replay(day, day.default_input(), &args)?;
+        }
+        ["replay", which, input] => replay(parse_day(which)?, PathBuf::from(input), &args)?,
+        ["record", which, input, part, verdict, answer] => {
+            record(parse_day(which)?, input, part, verdict, answer)?;
+        }
+        [which] => {
+            let day = parse_day(which)?;
+            run_day(day, day.default_input(), args.part)?;
+        }
+        [which, input] => {
+            run_day(parse_day(which)?, PathBuf::from(input), args.part)?;
+        }
+        _ => bail!("{USAGE}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+// runs every day against each of its inputNN.txt files and compares the
+// answers with the correct or pinned ones in the day's answers.txt ledger
+// a part without either on record fails unless it's listed in KNOWN_GAPS
+
+use anyhow::{anyhow, Error};
+use aoc::{Day, DAYS};
+use std::path::{Path, PathBuf};
+use tools::{Ledger, ParseError, Part};
+
+const FULL_INPUT: &str = "input02.txt";
+
+// (day, input, part, why there's no answer to check against)
+const KNOWN_GAPS: &[(u32, &str, u32, &str)] = &[
+    (3, "input01.txt", 2, "the example has no do() or don't()"),
+    (4, "input01.txt", 1, "part one isn't solved"),
+    (4, "input02.txt", 1, "part one isn't solved"),
+    (4, "input03.txt", 1, "part one isn't solved"),
+    (7, "input02.txt", 2, "the answer it gives is wrong"),
+    (9, "input01.txt", 1, "part one isn't solved"),
+    (9, "input02.txt", 1, "part one isn't solved"),
+    (13, "input01.txt", 2, "part two isn't solved"),
+    (13, "input02.txt", 2, "part two isn't solved"),
+    (14, "input01.txt", 2, "the example never draws a tree"),
+    (15, "input00.txt", 2, "part two isn't solved"),
+    (15, "input01.txt", 2, "part two isn't solved"),
+    (15, "input02.txt", 2, "part two isn't solved"),
+    (17, "input01.txt", 2, "part two has its own example"),
+    (17, "input02.txt", 2, "part two isn't solved"),
+    (18, "input01.txt", 1, "the 7x7 example grid isn't supported"),
+    (18, "input01.txt", 2, "the 7x7 example grid isn't supported"),
+    (22, "input01.txt", 2, "part two's example is input03.txt"),
+    (24, "input00.txt", 2, "part two isn't solved"),
+    (24, "input01.txt", 2, "part two isn't solved"),
+    (24, "input02.txt", 2, "part two isn't solved"),
+    (25, "input01.txt", 2, "there's no part two"),
+    (25, "input02.txt", 2, "there's no part two"),
+];
+
+fn is_known_gap(day: &Day, name: &str, part: u32) -> bool {
+    KNOWN_GAPS.iter().any(|&(number, input, gap_part, _why)| {
+        number == day.number && input == name && gap_part == part
+    })
+}
+
+// every inputNN.txt in the day's directory, by name
+fn discover_inputs(day: &Day) -> Result<Vec<(String, PathBuf)>, Error> {
+    let mut inputs = Vec::new();
+
+    for entry in std::fs::read_dir(day.dir())? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        let is_input = name
+            .strip_prefix("input")
+            .and_then(|rest| rest.strip_suffix(".txt"))
+            .is_some_and(|num| !num.is_empty() && num.bytes().all(|ch| ch.is_ascii_digit()));
+
+        if is_input {
+            inputs.push((name.to_owned(), path));
+        }
+    }
+
+    inputs.sort();
+    Ok(inputs)
+}
+
+// returns a description of each mismatch or missing answer
+fn check_input(day: &Day, ledger: &Ledger, name: &str, path: &Path) -> Result<Vec<String>, Error> {
+    let expected = [1, 2].map(|part| ledger.expected(name, part).or(ledger.pinned(name, part)));
+    let mut failures = Vec::new();
+
+    for (part, expected) in (1..).zip(&expected) {
+        match (expected, is_known_gap(day, name, part)) {
+            (None, false) => failures.push(format!("part {part}: no correct or pinned answer")),
+            (Some(_), true) => {
+                failures.push(format!("part {part}: answered, drop it from KNOWN_GAPS"))
+            }
+            _ => (),
+        }
+    }
+
+    let part = match expected {
+        [Some(_), Some(_)] => Part::Both,
+        [Some(_), None] => Part::One,
+        [None, Some(_)] => Part::Two,
+        [None, None] => return Ok(failures),
+    };
+
+    let text = std::fs::read_to_string(path)?;
+    let answers = (day.solve)(&text, &ledger.settings(name), part)
+        .map_err(|err| ParseError::in_source(err, &path.display().to_string()))?;
+
+    for ((part, expected), answer) in (1..)
+        .zip(expected)
+        .zip([answers.part_one, answers.part_two])
+    {
+        let (Some(expected), Some(answer)) = (expected, answer) else {
+            continue;
+        };
+
+        match answer {
+            Ok(answer) if answer == expected => (),
+            Ok(answer) => failures.push(format!("part {part}: expected {expected}, got {answer}")),
+            Err(err) => failures.push(format!("part {part}: expected {expected}, failed: {err:#}")),
+        }
+    }
+
+    Ok(failures)
+}
+
+fn check_all(include: impl Fn(&str) -> bool) {
+    let mut failures = Vec::new();
+    let mut num_checked = 0;
+
+    for day in DAYS {
+        let result = Ledger::load(&day.ledger_path()).and_then(|ledger| {
+            for (name, path) in discover_inputs(day)? {
+                if !include(&name) {
+                    continue;
+                }
+
+                num_checked += 1;
+                let input_failures = check_input(day, &ledger, &name, &path)
+                    .map_err(|err| anyhow!("{name}: {err:#}"))?;
+
+                for failure in input_failures {
+                    failures.push(format!("day {:02} {name} {failure}", day.number));
+                }
+            }
+
+            Ok(())
+        });
+
+        if let Err(err) = result {
+            failures.push(format!("day {:02}: {err:#}", day.number));
+        }
+    }
+
+    assert!(num_checked > 0, "no inputs found");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn examples() {
+    check_all(|name| name != FULL_INPUT);
+}
+
+// the full inputs take minutes, run with
+//   cargo test --release -p aoc -- --ignored
+#[test]
+#[ignore]
+fn full_inputs() {
+    check_all(|name| name == FULL_INPUT);
+}
@@ -0,0 +1,70 @@
+use anyhow::Error;
+use nom::character::complete::space1;
+use nom::sequence::separated_pair;
+use std::collections::HashMap;
+use tools::parse::{parse_all, parse_lines, signed};
+
+fn count_counts(nums: &[i64]) -> HashMap<i64, i64> {
+    let mut counts = HashMap::new();
+
+    for num in nums {
+        let count = counts.entry(*num).or_default();
+        *count += 1;
+    }
+
+    counts
+}
+
+pub struct Lists {
+    left: Vec<i64>,
+    right: Vec<i64>,
+}
+
+pub struct Day01;
+
+impl tools::Solution for Day01 {
+    type Input = Lists;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Lists, Error> {
+        let pairs: Vec<(i64, i64)> = parse_lines(text, |ln| {
+            parse_all(ln, separated_pair(signed, space1, signed))
+        })?;
+        let (mut left_lst, mut right_lst): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+
+        left_lst.sort();
+        right_lst.sort();
+
+        Ok(Lists {
+            left: left_lst,
+            right: right_lst,
+        })
+    }
+
+    fn part_one(lists: &Lists) -> Result<i64, Error> {
+        let mut total_dist: i64 = 0;
+
+        for (left_num, right_num) in lists.left.iter().zip(lists.right.iter()) {
+            let dist = (*left_num - *right_num).abs();
+
+            total_dist += dist;
+        }
+
+        Ok(total_dist)
+    }
+
+    fn part_two(lists: &Lists) -> Result<i64, Error> {
+        let right_counts = count_counts(&lists.right);
+
+        let mut sim_score: i64 = 0;
+
+        for left_num in &lists.left {
+            let right_count = right_counts.get(left_num).copied().unwrap_or_default();
+
+            sim_score += *left_num * right_count;
+        }
+
+        Ok(sim_score)
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::Error;
+use std::str::FromStr;
+use tools::parse::{list, parse_all, parse_lines, unsigned};
+
+pub struct Report {
+    levels: Vec<usize>,
+}
+
+fn map_index(idx: usize, excluded_idx: Option<usize>) -> usize {
+    if let Some(excluded_idx) = excluded_idx {
+        // if we're pretending an index was deleted, indices >= the deleted index are at idx + 1 in the physical array
+        if idx < excluded_idx {
+            idx
+        } else {
+            idx + 1
+        }
+    } else {
+        // if we're not pretending anything was deleted, no mapping is needed
+        idx
+    }
+}
+
+fn all_pairs_excluding<T>(
+    items: &[T],
+    pred: impl Fn(&T, &T) -> bool,
+    excluded_idx: Option<usize>,
+) -> bool {
+    for idx in 0..items.len() - 1 {
+        let idx_1 = map_index(idx, excluded_idx);
+        let idx_2 = map_index(idx + 1, excluded_idx);
+
+        let Some(item_1) = items.get(idx_1) else {
+            break;
+        };
+
+        let Some(item_2) = items.get(idx_2) else {
+            break;
+        };
+
+        if !pred(item_1, item_2) {
+            return false;
+        }
+    }
+    true
+}
+
+impl Report {
+    fn is_safe_excluding(&self, excluded_idx: Option<usize>) -> bool {
+        let all_incr = all_pairs_excluding(&self.levels, |a, b| *a > *b, excluded_idx);
+        let all_decr = all_pairs_excluding(&self.levels, |a, b| *a < *b, excluded_idx);
+
+        if !(all_incr || all_decr) {
+            return false;
+        }
+
+        all_pairs_excluding(
+            &self.levels,
+            |a, b| matches!(a.abs_diff(*b), 1..=3),
+            excluded_idx,
+        )
+    }
+}
+
+impl FromStr for Report {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let levels = parse_all(s, list(" ", unsigned))?;
+        Ok(Self { levels })
+    }
+}
+
+pub struct Day02;
+
+impl tools::Solution for Day02 {
+    type Input = Vec<Report>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Report>, Error> {
+        parse_lines(text, Report::from_str)
+    }
+
+    fn part_one(reports: &Vec<Report>) -> Result<usize, Error> {
+        let num_safe = reports
+            .iter()
+            .filter(|report| report.is_safe_excluding(None))
+            .count();
+
+        Ok(num_safe)
+    }
+
+    fn part_two(reports: &Vec<Report>) -> Result<usize, Error> {
+        let num_safe = reports
+            .iter()
+            .filter(|report| {
+                (0..report.levels.len())
+                    .any(|excluded_idx| report.is_safe_excluding(Some(excluded_idx)))
+            })
+            .count();
+
+        Ok(num_safe)
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Error;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while_m_n};
+use nom::character::complete::char;
+use nom::combinator::{map, map_res, value};
+use nom::sequence::{delimited, separated_pair};
+use nom::IResult;
+use std::str::FromStr as _;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instr {
+    Mul(usize, usize),
+    Do,
+    Dont,
+}
+
+// one to three digits
+fn parse_num(input: &str) -> IResult<&str, usize> {
+    map_res(
+        take_while_m_n(1, 3, |ch: char| ch.is_ascii_digit()),
+        usize::from_str,
+    )(input)
+}
+
+fn parse_mul(input: &str) -> IResult<&str, Instr> {
+    map(
+        delimited(
+            tag("mul("),
+            separated_pair(parse_num, char(','), parse_num),
+            char(')'),
+        ),
+        |(lhs, rhs)| Instr::Mul(lhs, rhs),
+    )(input)
+}
+
+fn parse_instr(input: &str) -> IResult<&str, Instr> {
+    alt((
+        parse_mul,
+        value(Instr::Do, tag("do()")),
+        value(Instr::Dont, tag("don't()")),
+    ))(input)
+}
+
+fn sum_muls(instrs: &[Instr], with_conditionals: bool) -> usize {
+    let mut sum = 0;
+
+    let mut mul_enabled = true;
+
+    for instr in instrs {
+        match instr {
+            Instr::Do if with_conditionals => mul_enabled = true,
+            Instr::Dont if with_conditionals => mul_enabled = false,
+            Instr::Mul(lhs, rhs) if mul_enabled => sum += lhs * rhs,
+            _ => {}
+        }
+    }
+
+    sum
+}
+
+pub struct Day03;
+
+impl tools::Solution for Day03 {
+    type Input = Vec<Instr>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // the memory is corrupted, so anything that isn't an instruction is skipped
+    fn parse(text: &str) -> Result<Vec<Instr>, Error> {
+        Ok(tools::parse::find_all(text, parse_instr))
+    }
+
+    fn part_one(instrs: &Vec<Instr>) -> Result<usize, Error> {
+        Ok(sum_muls(instrs, false))
+    }
+
+    fn part_two(instrs: &Vec<Instr>) -> Result<usize, Error> {
+        Ok(sum_muls(instrs, true))
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::{bail, Error};
+use std::str::FromStr as _;
+use tools::{Grid, Stencil};
+
+fn eval_x_2(grid: &Grid<char>, diagonals: &Stencil, pos: (usize, usize)) -> bool {
+    // center must be an A
+    if grid[pos] != 'A' {
+        return false;
+    }
+
+    // clockwise from up-left, all four must be on the grid
+    let corners: Option<Vec<char>> = diagonals
+        .cells(pos, grid.dim())
+        .map(|corner| Some(grid[corner?]))
+        .collect();
+    let Some(&[ul, ur, dr, dl]) = corners.as_deref() else {
+        return false;
+    };
+
+    // corners must be S or M
+    if [ul, ur, dr, dl].iter().any(|ch| *ch != 'S' && *ch != 'M') {
+        return false;
+    }
+
+    // opposite corners must not match (e.g. MAM or SAS)
+    if ul == dr {
+        return false;
+    }
+
+    if ur == dl {
+        return false;
+    }
+
+    true
+}
+
+pub struct Day04;
+
+impl tools::Solution for Day04 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid<char>, Error> {
+        Grid::from_str(text)
+    }
+
+    fn part_one(_grid: &Grid<char>) -> Result<usize, Error> {
+        bail!("not solved yet");
+    }
+
+    fn part_two(grid: &Grid<char>) -> Result<usize, Error> {
+        let diagonals = Stencil::diagonals();
+
+        let num_found = grid
+            .positions()
+            .filter(|pos| eval_x_2(grid, &diagonals, *pos))
+            .count();
+
+        Ok(num_found)
+    }
+}
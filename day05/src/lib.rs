@@ -0,0 +1,103 @@
+use anyhow::Error;
+use std::collections::HashMap;
+use tools::parse::{comma_list, parse_all, rule, unsigned};
+use tools::Sections;
+
+fn parse_rule(ln: &str) -> Result<(usize, usize), Error> {
+    parse_all(ln, rule('|'))
+}
+
+fn parse_update(ln: &str) -> Result<Vec<usize>, Error> {
+    parse_all(ln, comma_list(unsigned))
+}
+
+// returns true if a change was required
+fn fix_update(update: &mut [usize], rules: &[(usize, usize)]) -> bool {
+    let mut page_to_index: HashMap<usize, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(t, page)| (*page, t))
+        .collect();
+
+    let mut changed = false;
+
+    loop {
+        let mut changed_this_cycle = false;
+
+        for (page_x, page_y) in rules {
+            let [Some(page_x_index), Some(page_y_index)] =
+                page_to_index.get_disjoint_mut([page_x, page_y])
+            else {
+                continue;
+            };
+
+            if *page_x_index >= *page_y_index {
+                // x not printed before y, rule broken
+                // get slightly closely to an ok update by swapping the two
+                update.swap(*page_x_index, *page_y_index);
+                std::mem::swap(page_x_index, page_y_index);
+
+                changed = true;
+                changed_this_cycle = true;
+            }
+        }
+
+        if !changed_this_cycle {
+            break;
+        }
+    }
+
+    changed
+}
+
+fn get_update_middle_page(update: &[usize]) -> usize {
+    assert!(update.len() % 2 == 1);
+    update[update.len() / 2]
+}
+
+pub struct Manual {
+    rules: Vec<(usize, usize)>,
+    updates: Vec<Vec<usize>>,
+}
+
+pub struct Day05;
+
+impl tools::Solution for Day05 {
+    type Input = Manual;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Manual, Error> {
+        let mut sections = Sections::new(text);
+        let rules = sections.next_lines(parse_rule)?;
+        let updates = sections.next_lines(parse_update)?;
+        sections.finish()?;
+        Ok(Manual { rules, updates })
+    }
+
+    fn part_one(manual: &Manual) -> Result<usize, Error> {
+        let mut p1_res = 0;
+
+        for update in &manual.updates {
+            let mut copy = update.clone();
+            if !fix_update(&mut copy, &manual.rules) {
+                p1_res += get_update_middle_page(update);
+            }
+        }
+
+        Ok(p1_res)
+    }
+
+    fn part_two(manual: &Manual) -> Result<usize, Error> {
+        let mut p2_res = 0;
+
+        for update in &manual.updates {
+            let mut copy = update.clone();
+            if fix_update(&mut copy, &manual.rules) {
+                p2_res += get_update_middle_page(&copy);
+            }
+        }
+
+        Ok(p2_res)
+    }
+}
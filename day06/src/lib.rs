@@ -0,0 +1,166 @@
+use anyhow::{bail, Error};
+use std::str::FromStr as _;
+use tools::{BitGrid, Cell, DirSet, Direction, Grid, NoRecording, Recorder, Recording};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Open,
+    Obstacle,
+    Guard(Direction),
+}
+
+impl Cell for Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Tile::Open),
+            '#' => Some(Tile::Obstacle),
+            _ => Direction::from_arrow(ch).map(Tile::Guard),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Open => '.',
+            Tile::Obstacle => '#',
+            Tile::Guard(dir) => dir.to_arrow(),
+        }
+    }
+}
+
+fn find_start(grid: &Grid<Tile>) -> Result<((usize, usize), Direction), Error> {
+    let pos = grid.find_unique(|tile| matches!(tile, Tile::Guard(_)))?;
+    let Tile::Guard(dir) = grid[pos] else {
+        unreachable!();
+    };
+
+    Ok((pos, dir))
+}
+
+// the guard's next position and heading, None once they walk off the grid
+fn guard_step(
+    grid: &Grid<Tile>,
+    (pos, dir): ((usize, usize), Direction),
+) -> Option<((usize, usize), Direction)> {
+    let next = grid.step(pos, dir)?;
+
+    if grid[next] == Tile::Obstacle {
+        // obstacle in way, turn
+        Some((pos, dir.turn_right()))
+    } else {
+        Some((next, dir))
+    }
+}
+
+// `seen` is scratch space, cleared here so sweeps can share one
+fn guard_loops(
+    grid: &Grid<Tile>,
+    start: (usize, usize),
+    dir: Direction,
+    seen: &mut DirSet,
+) -> bool {
+    seen.clear();
+
+    let mut state = Some((start, dir));
+    while let Some((pos, dir)) = state {
+        if !seen.insert(pos, dir) {
+            return true;
+        }
+        state = guard_step(grid, (pos, dir));
+    }
+
+    false
+}
+
+// the positions the guard visits on the way out
+fn walk_out(
+    grid: &Grid<Tile>,
+    start: (usize, usize),
+    dir: Direction,
+    rec: &mut impl Recorder,
+) -> Result<BitGrid, Error> {
+    if guard_loops(grid, start, dir, &mut DirSet::new(grid.dim())) {
+        bail!("guard never escapes");
+    }
+
+    let mut visited_pos = BitGrid::new(grid.dim());
+    visited_pos.insert(start);
+
+    let mut state = (start, dir);
+    while let Some((pos, dir)) = guard_step(grid, state) {
+        if pos == state.0 {
+            rec.set(pos, Tile::Guard(dir).to_char());
+            rec.end_frame(|| format!("turn at {pos:?}"));
+        } else {
+            rec.set(state.0, 'X');
+            rec.set(pos, Tile::Guard(dir).to_char());
+            visited_pos.insert(pos);
+            rec.end_frame(|| format!("move to {pos:?}, {} visited", visited_pos.count()));
+        }
+        state = (pos, dir);
+    }
+
+    Ok(visited_pos)
+}
+
+pub struct Day06;
+
+impl tools::Solution for Day06 {
+    type Input = Grid<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid<Tile>, Error> {
+        Grid::from_str(text)
+    }
+
+    fn part_one(grid: &Grid<Tile>) -> Result<usize, Error> {
+        let (start, dir) = find_start(grid)?;
+
+        Ok(walk_out(grid, start, dir, &mut NoRecording)?.count())
+    }
+
+    fn part_two(grid: &Grid<Tile>) -> Result<usize, Error> {
+        let (start, dir) = find_start(grid)?;
+        // an obstacle anywhere else is never run into
+        let path = walk_out(grid, start, dir, &mut NoRecording)?;
+
+        let mut grid = grid.clone();
+        let mut seen = DirSet::new(grid.dim());
+        let mut num_loops = 0;
+        for pos in path.iter() {
+            let tile = grid[pos];
+
+            match tile {
+                Tile::Obstacle => {
+                    // obstacle already here
+                    continue;
+                }
+                Tile::Guard(_) => {
+                    //start point already here
+                    continue;
+                }
+                Tile::Open => (),
+            }
+
+            grid[pos] = Tile::Obstacle;
+            if guard_loops(&grid, start, dir, &mut seen) {
+                num_loops += 1;
+            }
+
+            grid[pos] = tile;
+        }
+
+        Ok(num_loops)
+    }
+
+    fn record(grid: &Grid<Tile>, part: u32) -> Result<Recording, Error> {
+        if part != 1 {
+            bail!("only part 1 is recorded");
+        }
+
+        let (start, dir) = find_start(grid)?;
+        let mut rec = Recording::new(grid);
+        walk_out(grid, start, dir, &mut rec)?;
+        Ok(rec)
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::Error;
+use std::fmt::Write as _;
+use std::str::FromStr as _;
+use tools::parse::{int_list, key_value, parse_all, parse_lines, unsigned};
+use tools::Memo;
+
+pub struct Equation {
+    test_value: usize,
+    args: Vec<usize>,
+}
+
+fn do_add(lhs: usize, rhs: usize) -> usize {
+    lhs.checked_add(rhs).unwrap()
+}
+
+fn do_mul(lhs: usize, rhs: usize) -> usize {
+    lhs.checked_mul(rhs).unwrap()
+}
+
+fn do_append(lhs: usize, rhs: usize) -> usize {
+    let mut res = lhs.to_string();
+    write!(&mut res, "{}", rhs).unwrap();
+    usize::from_str(&res).unwrap()
+}
+
+// keyed by (sum so far, index of the next arg), which only means something for one equation
+type EvalMemo = Memo<(usize, usize), bool>;
+
+fn eval_equation(eqn: &Equation, with_append: bool, memo: &mut EvalMemo) -> bool {
+    memo.get((eqn.args[0], 1), |eval, (cur_sum, next_arg)| {
+        if next_arg >= eqn.args.len() {
+            // no more args to process, check sum
+            return cur_sum == eqn.test_value;
+        }
+
+        let arg = eqn.args[next_arg];
+
+        eval((do_add(cur_sum, arg), next_arg + 1))
+            || eval((do_mul(cur_sum, arg), next_arg + 1))
+            || (with_append && eval((do_append(cur_sum, arg), next_arg + 1)))
+    })
+}
+
+fn equation_satisfiable(eqn: &Equation, with_append: bool, memo: &mut EvalMemo) -> bool {
+    memo.clear();
+    eval_equation(eqn, with_append, memo)
+}
+
+fn calibration_result(eqns: &[Equation], with_append: bool) -> usize {
+    let mut memo = EvalMemo::new();
+    let mut sum = 0usize;
+
+    for eqn in eqns {
+        if equation_satisfiable(eqn, with_append, &mut memo) {
+            sum = sum.checked_add(eqn.test_value).unwrap();
+        }
+    }
+
+    sum
+}
+
+pub struct Day07;
+
+impl tools::Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Equation>, Error> {
+        parse_lines(text, |ln| {
+            let (test_value, args) = parse_all(ln, key_value(unsigned, ": ", int_list(" ")))?;
+            Ok(Equation { test_value, args })
+        })
+    }
+
+    fn part_one(eqns: &Vec<Equation>) -> Result<usize, Error> {
+        Ok(calibration_result(eqns, false))
+    }
+
+    fn part_two(eqns: &Vec<Equation>) -> Result<usize, Error> {
+        Ok(calibration_result(eqns, true))
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::Error;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr as _;
+use tools::{BoundingBox, Grid, SparseGrid};
+
+type Antennas = HashMap<char, Vec<(i64, i64)>>;
+type AntiNodes = HashSet<(i64, i64)>;
+
+type FindAntiNodes = fn((i64, i64), (i64, i64), &mut AntiNodes, &BoundingBox);
+
+// the antennas, and the edges of the map they're on
+pub struct Map {
+    antennas: SparseGrid<char>,
+    bounds: BoundingBox,
+}
+
+fn find_antennas(map: &Map) -> Antennas {
+    let mut antennas: Antennas = HashMap::new();
+    for (pos, freq) in map.antennas.iter() {
+        antennas.entry(*freq).or_default().push(pos);
+    }
+
+    antennas
+}
+
+fn find_anti_node(a1: (i64, i64), a2: (i64, i64)) -> (i64, i64) {
+    let r = a1.0 + (a2.0 - a1.0) * 2;
+    let c = a1.1 + (a2.1 - a1.1) * 2;
+    (r, c)
+}
+
+fn find_anti_nodes_p1(
+    a1: (i64, i64),
+    a2: (i64, i64),
+    anti_nodes: &mut AntiNodes,
+    bounds: &BoundingBox,
+) {
+    let n = find_anti_node(a1, a2);
+
+    if bounds.contains(n) {
+        anti_nodes.insert(n);
+    }
+}
+
+fn find_anti_nodes_p2(
+    a1: (i64, i64),
+    a2: (i64, i64),
+    anti_nodes: &mut AntiNodes,
+    bounds: &BoundingBox,
+) {
+    let dr = a2.0 - a1.0;
+    let dc = a2.1 - a1.1;
+
+    let mut n = a1;
+
+    while bounds.contains(n) {
+        anti_nodes.insert(n);
+
+        n = (n.0 + dr, n.1 + dc);
+    }
+}
+
+fn count_anti_nodes(map: &Map, find_anti_nodes: FindAntiNodes) -> usize {
+    let antennas = find_antennas(map);
+
+    let mut anti_nodes = AntiNodes::new();
+
+    for antennae in antennas.values() {
+        for i in 0..antennae.len() {
+            for j in i + 1..antennae.len() {
+                find_anti_nodes(antennae[i], antennae[j], &mut anti_nodes, &map.bounds);
+                find_anti_nodes(antennae[j], antennae[i], &mut anti_nodes, &map.bounds);
+            }
+        }
+    }
+
+    anti_nodes.len()
+}
+
+pub struct Day08;
+
+impl tools::Solution for Day08 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Map, Error> {
+        let grid: Grid<char> = Grid::from_str(text)?;
+        let antennas = SparseGrid::from_dense(&grid, |freq| freq.is_ascii_alphanumeric());
+
+        Ok(Map {
+            antennas,
+            bounds: BoundingBox::of_dim(grid.dim()),
+        })
+    }
+
+    fn part_one(map: &Map) -> Result<usize, Error> {
+        Ok(count_anti_nodes(map, find_anti_nodes_p1))
+    }
+
+    fn part_two(map: &Map) -> Result<usize, Error> {
+        Ok(count_anti_nodes(map, find_anti_nodes_p2))
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::{bail, Error};
+use tools::ParseError;
+
+#[derive(Debug, Clone, Hash)]
+pub struct File {
+    file_id: usize,
+    length: usize,
+    disk_block: usize,
+}
+
+fn parse_disk_map(disk_map: &str) -> Result<Vec<File>, Error> {
+    // the map is a single line, errors point at the offending digit
+    let lengths: Vec<usize> = disk_map
+        .chars()
+        .enumerate()
+        .map(|(idx, ch)| {
+            let what = if idx.is_multiple_of(2) {
+                "used"
+            } else {
+                "free"
+            };
+            let length = ch.to_digit(10).ok_or_else(|| {
+                ParseError::new(format!("invalid {what}"))
+                    .with_line(1, disk_map)
+                    .with_col(idx + 1)
+            })?;
+            Ok(usize::try_from(length)?)
+        })
+        .collect::<Result<_, Error>>()?;
+
+    // used then free, with no free after the last file
+    let (pairs, last) = tools::array_chunks(&lengths);
+    let used_free = pairs
+        .map(|[used, free]| (*used, *free))
+        .chain(last.iter().map(|used| (*used, 0)));
+
+    let mut blocks = Vec::new();
+    let mut disk_block = 0;
+
+    for (file_id, (length, free)) in used_free.enumerate() {
+        blocks.push(File {
+            file_id,
+            length,
+            disk_block,
+        });
+
+        disk_block += length + free;
+    }
+
+    Ok(blocks)
+}
+
+fn find_contig_free_of_size(files: &[File], len: usize) -> Option<usize> {
+    for [a, b] in tools::array_windows(files) {
+        let a_end = a.disk_block + a.length;
+        let b_begin = b.disk_block;
+        let num_free = b_begin - a_end;
+
+        if num_free >= len {
+            return Some(a_end);
+        }
+    }
+
+    None
+}
+
+pub struct Day09;
+
+impl tools::Solution for Day09 {
+    type Input = Vec<File>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<File>, Error> {
+        parse_disk_map(text.trim_end())
+    }
+
+    fn part_one(_files: &Vec<File>) -> Result<usize, Error> {
+        bail!("not solved yet");
+    }
+
+    fn part_two(files: &Vec<File>) -> Result<usize, Error> {
+        let mut files = files.clone();
+        // kept in disk_block order
+        for file_id in (0..files.len()).rev() {
+            let idx = files
+                .iter()
+                .position(|file| file.file_id == file_id)
+                .unwrap();
+            let Some(free_block) = find_contig_free_of_size(&files, files[idx].length) else {
+                continue;
+            };
+
+            if free_block >= files[idx].disk_block {
+                // we only move files left
+                continue;
+            }
+
+            files[idx].disk_block = free_block;
+            files.sort_by_key(|block| block.disk_block);
+        }
+
+        let mut sum = 0;
+
+        for file in &files {
+            for file_block in 0..file.length {
+                sum += (file.disk_block + file_block) * file.file_id
+            }
+        }
+
+        Ok(sum)
+    }
+}
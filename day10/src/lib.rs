@@ -0,0 +1,115 @@
+use anyhow::Error;
+use std::collections::HashSet;
+use std::str::FromStr as _;
+use tools::{BitGrid, Cell, Direction, Grid};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Height(u32);
+
+impl Cell for Height {
+    fn from_char(ch: char) -> Option<Self> {
+        ch.to_digit(10).map(Height)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(self.0, 10).unwrap()
+    }
+}
+
+struct Work {
+    pos: (usize, usize),
+    path: String,
+}
+
+fn amend_path(path: &str, dir: Direction) -> String {
+    let mut path = path.to_owned();
+    path.push(dir.to_arrow());
+    path
+}
+
+// `seen` is scratch space, shared between trailheads
+fn eval_trailhead(
+    heights: &Grid<Height>,
+    nines: &BitGrid,
+    seen: &mut BitGrid,
+    pos: (usize, usize),
+    rating: bool,
+) -> usize {
+    seen.clear();
+    let mut work_queue = Vec::new();
+    let mut paths: HashSet<String> = HashSet::new();
+    work_queue.push(Work {
+        pos,
+        path: String::new(),
+    });
+
+    while let Some(work) = work_queue.pop() {
+        seen.insert(work.pos);
+        let height = heights[work.pos].0;
+        if height == 9 {
+            paths.insert(work.path.clone());
+        }
+
+        for dir in Direction::ALL {
+            let Some(pos) = heights.step(work.pos, dir) else {
+                continue;
+            };
+
+            let new_height = heights[pos].0;
+
+            if new_height == height + 1 {
+                work_queue.push(Work {
+                    pos,
+                    path: amend_path(&work.path, dir),
+                });
+            }
+        }
+    }
+
+    if rating {
+        paths.len()
+    } else {
+        // the nines reached
+        seen.intersect_with(nines);
+        seen.count()
+    }
+}
+
+fn sum_trailheads(heights: &Grid<Height>, rating: bool) -> usize {
+    let mut nines = BitGrid::new(heights.dim());
+    for (pos, val) in heights.iter() {
+        if *val == Height(9) {
+            nines.insert(pos);
+        }
+    }
+
+    let mut seen = BitGrid::new(heights.dim());
+    let mut total_score = 0;
+    for (pos, val) in heights.iter() {
+        if *val == Height(0) {
+            total_score += eval_trailhead(heights, &nines, &mut seen, pos, rating);
+        }
+    }
+
+    total_score
+}
+
+pub struct Day10;
+
+impl tools::Solution for Day10 {
+    type Input = Grid<Height>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid<Height>, Error> {
+        Grid::from_str(text)
+    }
+
+    fn part_one(heights: &Grid<Height>) -> Result<usize, Error> {
+        Ok(sum_trailheads(heights, false))
+    }
+
+    fn part_two(heights: &Grid<Height>) -> Result<usize, Error> {
+        Ok(sum_trailheads(heights, true))
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::{anyhow, Error};
+use std::str::FromStr as _;
+use tools::parse::{list, parse_all, parse_lines, unsigned};
+use tools::{Memo, Recurse};
+
+fn split_num(val: usize) -> Option<(usize, usize)> {
+    let str_val = val.to_string();
+    if str_val.len().is_multiple_of(2) {
+        let (left, right) = str_val.split_at(str_val.len() / 2);
+        let left = usize::from_str(left).unwrap();
+        let right = usize::from_str(right).unwrap();
+        Some((left, right))
+    } else {
+        None
+    }
+}
+
+// how many pebbles `num` turns into after `blinks` blinks, None if they overflow
+fn blink_pebble(
+    blink: &mut Recurse<(usize, usize), Option<usize>>,
+    (num, blinks): (usize, usize),
+) -> Option<usize> {
+    if blinks == 0 {
+        return Some(1);
+    }
+
+    if num == 0 {
+        // rule 0
+        blink((1, blinks - 1))
+    } else if let Some((left, right)) = split_num(num) {
+        // rule 1
+        blink((left, blinks - 1))?.checked_add(blink((right, blinks - 1))?)
+    } else {
+        // rule 2
+        blink((num.checked_mul(2024)?, blinks - 1))
+    }
+}
+
+fn blink(input: &[usize], num_iters: usize) -> Result<usize, Error> {
+    // pebbles with the same number go the same way, whichever pebble they came from
+    let mut memo = Memo::new();
+    let mut total = 0usize;
+
+    for num in input {
+        let count = memo
+            .get((*num, num_iters), blink_pebble)
+            .ok_or_else(|| anyhow!("pebble count overflowed"))?;
+        total = total.checked_add(count).ok_or_else(|| anyhow!("bad add"))?;
+    }
+
+    Ok(total)
+}
+
+pub struct Day11;
+
+impl tools::Solution for Day11 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<usize>, Error> {
+        let lines = parse_lines(text, |ln| parse_all(ln, list(" ", unsigned)))?;
+        Ok(lines.concat())
+    }
+
+    fn part_one(input: &Vec<usize>) -> Result<usize, Error> {
+        blink(input, 25)
+    }
+
+    fn part_two(input: &Vec<usize>) -> Result<usize, Error> {
+        blink(input, 75)
+    }
+}
@@ -0,0 +1,32 @@
+use anyhow::Error;
+use std::str::FromStr as _;
+use tools::{label_components, Grid, RegionGeometry};
+
+// each region's area times what it costs to fence, by edge or by side
+fn fencing_price(plots: &Grid<char>, fence: impl Fn(&RegionGeometry) -> usize) -> usize {
+    label_components(plots, |a, b| a == b)
+        .geometry()
+        .iter()
+        .map(|region| region.area * fence(region))
+        .sum()
+}
+
+pub struct Day12;
+
+impl tools::Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid<char>, Error> {
+        Grid::from_str(text)
+    }
+
+    fn part_one(plots: &Grid<char>) -> Result<usize, Error> {
+        Ok(fencing_price(plots, |region| region.perimeter))
+    }
+
+    fn part_two(plots: &Grid<char>) -> Result<usize, Error> {
+        Ok(fencing_price(plots, |region| region.sides))
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{bail, Error};
+use nom::bytes::complete::tag;
+use nom::sequence::preceded;
+use tools::parse::{axes, parse_all};
+use tools::Sections;
+
+#[derive(Debug)]
+struct Vec2 {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug)]
+pub struct Prize {
+    a_value: Vec2,
+    b_value: Vec2,
+    goal: Vec2,
+}
+
+fn load_prizes(text: &str) -> Result<Vec<Prize>, Error> {
+    Sections::new(text).parse_rest(|section| {
+        if section.lines().count() != 3 {
+            bail!("expected 3 lines per prize");
+        }
+
+        let (ax, ay) = section.parse_line(0, |ln| {
+            parse_all(ln, preceded(tag("Button A: "), axes('+')))
+        })?;
+        let (bx, by) = section.parse_line(1, |ln| {
+            parse_all(ln, preceded(tag("Button B: "), axes('+')))
+        })?;
+        let (px, py) =
+            section.parse_line(2, |ln| parse_all(ln, preceded(tag("Prize: "), axes('='))))?;
+
+        Ok(Prize {
+            a_value: Vec2 { x: ax, y: ay },
+            b_value: Vec2 { x: bx, y: by },
+            goal: Vec2 { x: px, y: py },
+        })
+    })
+}
+
+pub struct Day13;
+
+impl tools::Solution for Day13 {
+    type Input = Vec<Prize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Prize>, Error> {
+        load_prizes(text)
+    }
+
+    fn part_one(prizes: &Vec<Prize>) -> Result<usize, Error> {
+        let mut total_cost = 0;
+
+        for prize in prizes {
+            let mut best_cost: Option<usize> = None;
+            for num_a in 0..200 {
+                for num_b in 0..200 {
+                    let x_val = num_a * prize.a_value.x + num_b * prize.b_value.x;
+                    let y_val = num_a * prize.a_value.y + num_b * prize.b_value.y;
+                    if x_val == prize.goal.x && y_val == prize.goal.y {
+                        let this_cost = num_a * 3 + num_b;
+                        if let Some(best_cost) = &mut best_cost {
+                            *best_cost = std::cmp::min(*best_cost, this_cost);
+                        } else {
+                            best_cost = Some(this_cost);
+                        }
+                    }
+                }
+            }
+
+            total_cost += best_cost.unwrap_or_default();
+        }
+
+        Ok(total_cost)
+    }
+
+    fn part_two(_prizes: &Vec<Prize>) -> Result<usize, Error> {
+        bail!("not solved yet");
+    }
+}
@@ -0,0 +1,153 @@
+use anyhow::{anyhow, Error};
+use nom::character::complete::space1;
+use nom::sequence::separated_pair;
+use tools::parse::{labeled_xy, parse_all, parse_lines};
+use tools::{find_cycle, ParseError, Point, Settings, Vector};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Robot {
+    pos: Point,
+    vel: Vector,
+}
+
+// 101 wide and 103 tall, the examples set a smaller floor in the ledger
+const FULL_WIDTH: usize = 101;
+const FULL_HEIGHT: usize = 103;
+
+// the robots wrap around at the edges of the floor, dim is (rows, cols)
+pub struct Lobby {
+    robots: Vec<Robot>,
+    dim: (usize, usize),
+}
+
+fn read_robots(text: &str, (max_rows, max_cols): (usize, usize)) -> Result<Vec<Robot>, Error> {
+    parse_lines(text, |ln| {
+        let ((px, py), (vx, vy)) =
+            parse_all(ln, separated_pair(labeled_xy("p"), space1, labeled_xy("v")))?;
+
+        if py >= max_rows || px >= max_cols {
+            return Err(ParseError::new(format!(
+                "robot starts off the {max_cols} by {max_rows} floor"
+            ))
+            .with_col(1)
+            .into());
+        }
+
+        Ok(Robot {
+            pos: Point::new(py, px),
+            vel: Vector::new(vy, vx),
+        })
+    })
+}
+
+fn which_quadrant(pos: Point, (num_rows, num_cols): (usize, usize)) -> Option<usize> {
+    let half_x = (num_cols - 1) / 2;
+    let half_y = (num_rows - 1) / 2;
+    let (x, y) = (pos.col, pos.row);
+
+    if x < half_x && y < half_y {
+        Some(0)
+    } else if x > half_x && y > half_y {
+        Some(1)
+    } else if x > half_x && y < half_y {
+        Some(2)
+    } else if x < half_x && y > half_y {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+fn score_bots(robots: &[Robot], dim: (usize, usize)) -> usize {
+    let mut num_bots: [usize; 4] = [0; 4];
+
+    for robot in robots {
+        if let Some(quadrant) = which_quadrant(robot.pos, dim) {
+            num_bots[quadrant] += 1;
+        }
+    }
+
+    num_bots[0] * num_bots[1] * num_bots[2] * num_bots[3]
+}
+
+fn step_all_bots(robots: &mut [Robot], num_steps: usize, dim: (usize, usize)) {
+    for robot in robots {
+        robot.pos = robot.pos.wrapping_offset_times(robot.vel, num_steps, dim);
+    }
+}
+
+fn sqr_dist(a: Point, b: Point) -> i64 {
+    let diff = a.vector_to(b);
+
+    diff.dr * diff.dr + diff.dc * diff.dc
+}
+
+fn score_bot_dist(robots: &[Robot]) -> i64 {
+    let mut total_dist = 0;
+    for i in 0..robots.len() {
+        for j in i + 1..robots.len() {
+            total_dist += sqr_dist(robots[i].pos, robots[j].pos);
+        }
+    }
+
+    total_dist
+}
+
+pub struct Day14;
+
+impl tools::Solution for Day14 {
+    type Input = Lobby;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Lobby, Error> {
+        Self::parse_with(text, &Settings::default())
+    }
+
+    fn parse_with(text: &str, settings: &Settings) -> Result<Lobby, Error> {
+        let dim = (
+            settings.get_or("height", FULL_HEIGHT)?,
+            settings.get_or("width", FULL_WIDTH)?,
+        );
+
+        let robots = read_robots(text, dim)?;
+        Ok(Lobby { robots, dim })
+    }
+
+    fn part_one(lobby: &Lobby) -> Result<usize, Error> {
+        let mut bots_copy = lobby.robots.clone();
+
+        step_all_bots(&mut bots_copy, 100, lobby.dim);
+        Ok(score_bots(&bots_copy, lobby.dim))
+    }
+
+    fn part_two(lobby: &Lobby) -> Result<usize, Error> {
+        let mut bots_copy = lobby.robots.clone();
+        let mut best_score: Option<(usize, i64)> = None;
+
+        // the tree has to turn up before the robots start repeating themselves
+        let cycle = find_cycle(lobby.robots.clone(), |robots| {
+            let mut robots = robots.clone();
+            step_all_bots(&mut robots, 1, lobby.dim);
+            Some(robots)
+        })
+        .ok_or_else(|| anyhow!("robots never repeat"))?;
+
+        for this_step in 0..cycle.start + cycle.period {
+            let this_score = score_bot_dist(&bots_copy);
+            if let Some((best_step, best_score)) = &mut best_score {
+                if this_score < *best_score {
+                    *best_step = this_step;
+                    *best_score = this_score;
+                }
+            } else {
+                best_score = Some((this_step, this_score));
+            }
+            step_all_bots(&mut bots_copy, 1, lobby.dim);
+        }
+
+        let (step, _score) = best_score.ok_or_else(|| anyhow!("no steps taken"))?;
+
+        Ok(step)
+    }
+}
@@ -0,0 +1,333 @@
+use anyhow::{anyhow, bail, Error, Ok};
+use tools::{Cell, Direction, Grid, NoRecording, ParseError, Recorder, Recording, Sections};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Wall,
+    Floor,
+    Box,
+    // the two halves of a box in the widened warehouse
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+impl Cell for Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Floor),
+            'O' => Some(Tile::Box),
+            '[' => Some(Tile::BoxLeft),
+            ']' => Some(Tile::BoxRight),
+            '@' => Some(Tile::Robot),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Wall => '#',
+            Tile::Floor => '.',
+            Tile::Box => 'O',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+            Tile::Robot => '@',
+        }
+    }
+}
+
+fn push_box_p1(grid: &mut Grid<Tile>, pos: (usize, usize), dir: Direction) -> Result<bool, Error> {
+    let mut test_pos = pos;
+    loop {
+        match grid[test_pos] {
+            Tile::Wall => {
+                // can't push into wall
+                return Ok(false);
+            }
+            Tile::Floor => {
+                // found an empty space to push into
+                break;
+            }
+            Tile::Box => {
+                // found a box to push
+                let Some(new_pos) = grid.step(test_pos, dir) else {
+                    return Ok(false);
+                };
+                test_pos = new_pos;
+            }
+            _ => {
+                bail!("bad grid tile");
+            }
+        }
+    }
+    // the row of boxes moves along one, same as the first box swapping with the gap
+    let [first, gap] = grid
+        .get_disjoint_mut([pos, test_pos])
+        .ok_or_else(|| anyhow!("box pushed onto itself"))?;
+    std::mem::swap(first, gap);
+    Ok(true)
+}
+
+// we want to place ch at pos
+// in part two, box pos is the pos of the left bracket
+enum MyWorkItem {
+    Free { pos: (usize, usize) },
+    Box { pos: (usize, usize) },
+}
+
+struct HistoryItem {
+    old: Tile,
+    pos: (usize, usize),
+}
+
+struct LoggedGrid<'a> {
+    grid: &'a mut Grid<Tile>,
+    history: Vec<HistoryItem>,
+}
+
+impl<'a> LoggedGrid<'a> {
+    fn new(grid: &'a mut Grid<Tile>) -> Self {
+        let history = Vec::new();
+        Self { grid, history }
+    }
+
+    fn commit(&mut self) {
+        self.history.clear();
+    }
+
+    fn set(&mut self, pos: (usize, usize), tile: Tile) {
+        let item = HistoryItem {
+            old: self.grid[pos],
+            pos,
+        };
+
+        self.grid[pos] = tile;
+        self.history.push(item);
+    }
+}
+
+impl AsRef<Grid<Tile>> for LoggedGrid<'_> {
+    fn as_ref(&self) -> &Grid<Tile> {
+        self.grid
+    }
+}
+
+impl Drop for LoggedGrid<'_> {
+    fn drop(&mut self) {
+        for item in self.history.iter().rev() {
+            self.grid[item.pos] = item.old;
+        }
+    }
+}
+
+fn push_box_p2(grid: &mut Grid<Tile>, pos: (usize, usize), dir: Direction) -> Result<bool, Error> {
+    let mut queue: Vec<MyWorkItem> = Vec::new();
+    let mut grid = LoggedGrid::new(grid);
+
+    queue.push(MyWorkItem::Free { pos });
+    while let Some(work) = queue.pop() {
+        match work {
+            MyWorkItem::Free { pos } => {
+                match grid.as_ref()[pos] {
+                    Tile::Wall => {
+                        return Ok(false);
+                    }
+                    Tile::Floor => {
+                        // nothing to do
+                    }
+                    Tile::BoxLeft | Tile::BoxRight => {
+                        let (left_pos, right_pos) = if grid.as_ref()[pos] == Tile::BoxLeft {
+                            (pos, (pos.0, pos.1 + 1))
+                        } else {
+                            ((pos.0, pos.1 - 1), pos)
+                        };
+
+                        let Some(next_pos) = grid.as_ref().step(left_pos, dir) else {
+                            return Ok(false);
+                        };
+                        grid.set(left_pos, Tile::Floor);
+                        grid.set(right_pos, Tile::Floor);
+
+                        queue.push(MyWorkItem::Box { pos: next_pos });
+                    }
+                    _ => bail!("bad grid tile"),
+                }
+            }
+            MyWorkItem::Box { pos } => match grid.as_ref()[pos] {
+                Tile::Wall => {
+                    return Ok(false);
+                }
+                Tile::Floor => {}
+                _ => bail!("bad grid tile"),
+            },
+        }
+    }
+
+    grid.commit();
+    Ok(true)
+}
+
+fn do_move(
+    grid: &mut Grid<Tile>,
+    cur_pos: &mut (usize, usize),
+    dir: Direction,
+) -> Result<(), Error> {
+    let Some(new_pos) = grid.step(*cur_pos, dir) else {
+        return Ok(());
+    };
+    grid[*cur_pos] = Tile::Floor;
+
+    match grid[new_pos] {
+        Tile::Wall => {
+            // if we're walking into a wall, do nothing
+        }
+        Tile::Floor => {
+            // if we're moving onto a free space, do it
+            *cur_pos = new_pos;
+        }
+        Tile::Box => {
+            if push_box_p1(grid, new_pos, dir)? {
+                *cur_pos = new_pos;
+            }
+        }
+        Tile::BoxLeft | Tile::BoxRight => {
+            if push_box_p2(grid, new_pos, dir)? {
+                *cur_pos = new_pos;
+            }
+        }
+        Tile::Robot => {
+            bail!("bad grid tile");
+        }
+    }
+    grid[*cur_pos] = Tile::Robot;
+    Ok(())
+}
+
+// given position of right edge of box
+fn dist_from_top(_grid: &Grid<Tile>, pos: (usize, usize)) -> usize {
+    pos.0
+}
+
+// given position of right edge of box
+fn dist_from_right(grid: &Grid<Tile>, pos: (usize, usize)) -> usize {
+    grid.num_cols() - pos.1
+}
+
+fn calc_gps_sum(grid: &Grid<Tile>) -> usize {
+    let mut in_box = false;
+    let mut gps_sum = 0;
+    for (pos, tile) in grid.iter() {
+        match *tile {
+            Tile::Box => {
+                gps_sum += 100 * pos.0 + pos.1;
+            }
+            Tile::BoxLeft => {
+                assert!(!in_box);
+                in_box = true;
+            }
+            Tile::BoxRight => {
+                assert!(in_box);
+                in_box = false;
+                gps_sum += 100 * dist_from_top(grid, pos) + dist_from_right(grid, pos);
+            }
+            Tile::Robot | Tile::Wall | Tile::Floor => (),
+        }
+    }
+    gps_sum
+}
+
+fn widen_grid(old_grid: &Grid<Tile>) -> Result<Grid<Tile>, Error> {
+    let (num_rows, num_cols) = old_grid.dim();
+    let mut new_grid = Grid::from_elem((num_rows, num_cols * 2), Tile::Floor);
+
+    for (old_pos, old_tile) in old_grid.iter() {
+        let new_pos_1 = (old_pos.0, old_pos.1 * 2);
+        let new_pos_2 = (old_pos.0, old_pos.1 * 2 + 1);
+        let (new_tile_1, new_tile_2) = match *old_tile {
+            Tile::Wall => (Tile::Wall, Tile::Wall),
+            Tile::Box => (Tile::BoxLeft, Tile::BoxRight),
+            Tile::Floor => (Tile::Floor, Tile::Floor),
+            Tile::Robot => (Tile::Robot, Tile::Floor),
+            Tile::BoxLeft | Tile::BoxRight => bail!("grid already widened"),
+        };
+
+        new_grid[new_pos_1] = new_tile_1;
+        new_grid[new_pos_2] = new_tile_2;
+    }
+
+    Ok(new_grid)
+}
+
+fn run_instrs(
+    mut grid: Grid<Tile>,
+    instrs: &[Direction],
+    rec: &mut impl Recorder,
+) -> Result<usize, Error> {
+    let mut cur_pos = grid.find_unique(|tile| *tile == Tile::Robot)?;
+
+    for (instr_idx, dir) in instrs.iter().enumerate() {
+        do_move(&mut grid, &mut cur_pos, *dir)?;
+        rec.snapshot(&grid, || format!("move {instr_idx}: {dir}"));
+    }
+
+    Ok(calc_gps_sum(&grid))
+}
+
+pub struct Warehouse {
+    grid: Grid<Tile>,
+    instrs: Vec<Direction>,
+}
+
+fn parse_instrs(ln: &str) -> Result<Vec<Direction>, Error> {
+    ln.chars()
+        .enumerate()
+        .map(|(idx, instr)| {
+            Direction::from_arrow(instr).ok_or_else(|| {
+                ParseError::new(format!("bad instr {instr:?}"))
+                    .with_col(idx + 1)
+                    .into()
+            })
+        })
+        .collect()
+}
+
+pub struct Day15;
+
+impl tools::Solution for Day15 {
+    type Input = Warehouse;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Warehouse, Error> {
+        let mut sections = Sections::new(text);
+        let grid = sections.next_grid()?;
+        let instrs = sections.next_lines(parse_instrs)?.concat();
+        sections.finish()?;
+        Ok(Warehouse { grid, instrs })
+    }
+
+    fn part_one(warehouse: &Warehouse) -> Result<usize, Error> {
+        run_instrs(warehouse.grid.clone(), &warehouse.instrs, &mut NoRecording)
+    }
+
+    fn part_two(warehouse: &Warehouse) -> Result<usize, Error> {
+        let grid = widen_grid(&warehouse.grid)?;
+        run_instrs(grid, &warehouse.instrs, &mut NoRecording)
+    }
+
+    // a failing move still leaves the frames up to it
+    fn record(warehouse: &Warehouse, part: u32) -> Result<Recording, Error> {
+        let grid = match part {
+            1 => warehouse.grid.clone(),
+            _ => widen_grid(&warehouse.grid)?,
+        };
+
+        let mut rec = Recording::new(&grid);
+        if let Err(err) = run_instrs(grid, &warehouse.instrs, &mut rec) {
+            rec.end_frame(|| format!("failed: {err:#}"));
+        }
+
+        Ok(rec)
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::{anyhow, bail, Error};
+use std::collections::HashSet;
+use std::str::FromStr as _;
+use tools::{Cell, Direction, Grid};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Wall,
+    Floor,
+    Start,
+    End,
+}
+
+impl Cell for Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Floor),
+            'S' => Some(Tile::Start),
+            'E' => Some(Tile::End),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Wall => '#',
+            Tile::Floor => '.',
+            Tile::Start => 'S',
+            Tile::End => 'E',
+        }
+    }
+}
+
+// where the reindeer is and which way it faces
+type State = ((usize, usize), Direction);
+
+fn find_neighbors(grid: &Grid<Tile>, (pos, dir): State) -> Vec<(State, usize)> {
+    let mut neighbors = vec![
+        ((pos, dir.turn_left()), 1000),
+        ((pos, dir.turn_right()), 1000),
+    ];
+
+    // continue neighbor
+    if let Some(new_pos) = grid.step(pos, dir) {
+        if grid[new_pos] == Tile::Floor {
+            neighbors.push(((new_pos, dir), 1));
+        }
+    }
+
+    neighbors
+}
+
+pub struct Maze {
+    grid: Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+fn ucs(maze: &Maze) -> Result<usize, Error> {
+    let Maze { grid, start, end } = maze;
+
+    let found = tools::dijkstra(
+        (*start, Direction::Right),
+        |state| find_neighbors(grid, *state),
+        |(pos, _dir)| pos == end,
+    )
+    .ok_or_else(|| anyhow!("no path found"))?;
+
+    Ok(found.cost)
+}
+
+pub struct Day16;
+
+impl tools::Solution for Day16 {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Maze, Error> {
+        let mut grid = Grid::from_str(text)?;
+
+        let start = grid
+            .find_unique(|tile| *tile == Tile::Start)
+            .map_err(|err| anyhow!("start pos: {err}"))?;
+
+        let end = grid
+            .find_unique(|tile| *tile == Tile::End)
+            .map_err(|err| anyhow!("end pos: {err}"))?;
+
+        grid[start] = Tile::Floor;
+        grid[end] = Tile::Floor;
+
+        Ok(Maze { grid, start, end })
+    }
+
+    fn part_one(maze: &Maze) -> Result<usize, Error> {
+        ucs(maze)
+    }
+
+    // tiles on any of the best paths
+    fn part_two(maze: &Maze) -> Result<usize, Error> {
+        let Maze { grid, start, end } = maze;
+
+        let map = tools::dijkstra_all((*start, Direction::Right), |state| {
+            find_neighbors(grid, *state)
+        });
+
+        let ends = Direction::ALL.map(|dir| (*end, dir));
+
+        let tiles: HashSet<(usize, usize)> = map
+            .on_best_paths(ends)
+            .into_iter()
+            .map(|(pos, _dir)| pos)
+            .collect();
+
+        if tiles.is_empty() {
+            bail!("no path found");
+        }
+
+        Ok(tiles.len())
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::{bail, Error};
+use nom::bytes::complete::tag;
+use nom::sequence::{preceded, tuple};
+use tools::parse::{comma_list, parse_all, unsigned};
+use tools::Sections;
+
+pub struct Computer {
+    // a, b and c
+    registers: [usize; 3],
+    program: Vec<usize>,
+}
+
+fn combo(registers: &[usize; 3], operand: usize) -> Result<usize, Error> {
+    match operand {
+        0..=3 => Ok(operand),
+        4..=6 => Ok(registers[operand - 4]),
+        _ => bail!("invalid combo operand {operand}"),
+    }
+}
+
+// the a register divided by 2 to the power of the combo operand
+fn divide(registers: &[usize; 3], operand: usize) -> Result<usize, Error> {
+    let shift = combo(registers, operand)?;
+    Ok(u32::try_from(shift)
+        .ok()
+        .and_then(|shift| registers[0].checked_shr(shift))
+        .unwrap_or(0))
+}
+
+// runs the program until it steps off the end, returns what it output
+fn run(computer: &Computer) -> Result<Vec<usize>, Error> {
+    let program = &computer.program;
+    let mut registers = computer.registers;
+    let mut output = Vec::new();
+    let mut ip = 0;
+
+    while let (Some(&opcode), Some(&operand)) = (program.get(ip), program.get(ip + 1)) {
+        ip += 2;
+
+        match opcode {
+            // adv
+            0 => registers[0] = divide(&registers, operand)?,
+            // bxl
+            1 => registers[1] ^= operand,
+            // bst
+            2 => registers[1] = combo(&registers, operand)? % 8,
+            // jnz
+            3 => {
+                if registers[0] != 0 {
+                    ip = operand;
+                }
+            }
+            // bxc
+            4 => registers[1] ^= registers[2],
+            // out
+            5 => output.push(combo(&registers, operand)? % 8),
+            // bdv
+            6 => registers[1] = divide(&registers, operand)?,
+            // cdv
+            7 => registers[2] = divide(&registers, operand)?,
+            _ => bail!("invalid opcode {opcode}"),
+        }
+    }
+
+    Ok(output)
+}
+
+// "Register A: 729"
+fn parse_register(ln: &str, name: &str) -> Result<usize, Error> {
+    parse_all(
+        ln,
+        preceded(tuple((tag("Register "), tag(name), tag(": "))), unsigned),
+    )
+}
+
+pub struct Day17;
+
+impl tools::Solution for Day17 {
+    type Input = Computer;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Computer, Error> {
+        let mut sections = Sections::new(text);
+
+        let registers = sections.next_section()?;
+        let registers = [
+            registers.parse_line(0, |ln| parse_register(ln, "A"))?,
+            registers.parse_line(1, |ln| parse_register(ln, "B"))?,
+            registers.parse_line(2, |ln| parse_register(ln, "C"))?,
+        ];
+
+        let program = sections.next_section()?.parse_line(0, |ln| {
+            parse_all(ln, preceded(tag("Program: "), comma_list(unsigned)))
+        })?;
+        sections.finish()?;
+
+        Ok(Computer { registers, program })
+    }
+
+    fn part_one(computer: &Computer) -> Result<String, Error> {
+        let output: Vec<String> = run(computer)?.iter().map(|val| val.to_string()).collect();
+
+        Ok(output.join(","))
+    }
+
+    fn part_two(_computer: &Computer) -> Result<usize, Error> {
+        bail!("not solved yet");
+    }
+}
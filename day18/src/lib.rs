@@ -0,0 +1,124 @@
+use anyhow::{anyhow, bail, Error};
+use tools::parse::{parse_all, parse_lines, rule};
+use tools::{BitGrid, Direction, ParseError, UnionFind};
+
+const WIDTH: usize = 71;
+const HEIGHT: usize = 71;
+
+const END: (usize, usize) = (WIDTH - 1, HEIGHT - 1);
+
+fn find_neighbors(grid: &BitGrid, pos: (usize, usize)) -> Vec<((usize, usize), usize)> {
+    let mut result = Vec::new();
+
+    for dir in Direction::ALL {
+        let Some(new_pos) = grid.step(pos, dir) else {
+            continue;
+        };
+
+        if grid.get(new_pos) {
+            continue;
+        }
+
+        result.push((new_pos, 1));
+    }
+
+    result
+}
+
+// manhattan distance, never more than the real path
+fn distance_to_end(pos: &(usize, usize)) -> usize {
+    (END.0 - pos.0) + (END.1 - pos.1)
+}
+
+fn find_path(grid: &BitGrid) -> Option<usize> {
+    let found = tools::astar(
+        (0, 0),
+        |pos| find_neighbors(grid, *pos),
+        distance_to_end,
+        |pos| *pos == END,
+    )?;
+
+    Some(found.cost)
+}
+
+pub struct Day18;
+
+impl tools::Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(text: &str) -> Result<Vec<(usize, usize)>, Error> {
+        // "x,y"
+        parse_lines(text, |ln| {
+            let (x, y) = parse_all(ln, rule(','))?;
+
+            if x >= WIDTH {
+                return Err(
+                    ParseError::new(format!("x {x} is off the {WIDTH} wide grid"))
+                        .with_col(1)
+                        .into(),
+                );
+            }
+            if y >= HEIGHT {
+                let col = ln.find(',').map_or(1, |comma| comma + 2);
+                return Err(
+                    ParseError::new(format!("y {y} is off the {HEIGHT} high grid"))
+                        .with_col(col)
+                        .into(),
+                );
+            }
+
+            Ok((x, y))
+        })
+    }
+
+    fn part_one(drops: &Vec<(usize, usize)>) -> Result<usize, Error> {
+        let mut grid = BitGrid::new((WIDTH, HEIGHT));
+
+        for pos in drops.iter().copied().take(1024) {
+            grid.insert(pos);
+        }
+
+        find_path(&grid).ok_or_else(|| anyhow!("path not found"))
+    }
+
+    fn part_two(drops: &Vec<(usize, usize)>) -> Result<String, Error> {
+        // with every byte down, take them back up from the last one,
+        // the byte that joins the start to the end again is the one that cut them off
+        let mut grid = BitGrid::new((WIDTH, HEIGHT));
+        for pos in drops.iter().copied() {
+            grid.insert(pos);
+        }
+
+        let index = |(x, y): (usize, usize)| x * HEIGHT + y;
+        let mut open = UnionFind::new(WIDTH * HEIGHT);
+
+        let join_open = |open: &mut UnionFind, grid: &BitGrid, pos| {
+            for (next, _cost) in find_neighbors(grid, pos) {
+                open.union(index(pos), index(next));
+            }
+        };
+
+        for pos in (0..WIDTH).flat_map(|x| (0..HEIGHT).map(move |y| (x, y))) {
+            if !grid.get(pos) {
+                join_open(&mut open, &grid, pos);
+            }
+        }
+
+        if open.same_set(index((0, 0)), index(END)) {
+            bail!("path never blocked");
+        }
+
+        for (x, y) in drops.iter().copied().rev() {
+            grid.remove((x, y));
+            join_open(&mut open, &grid, (x, y));
+
+            if open.same_set(index((0, 0)), index(END)) {
+                return Ok(format!("{x},{y}"));
+            }
+        }
+
+        bail!("start is never reachable");
+    }
+}
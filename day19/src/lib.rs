@@ -0,0 +1,87 @@
+use anyhow::Error;
+use std::collections::HashMap;
+use tools::parse::{comma_list, parse_all, word};
+use tools::{Memo, Sections};
+
+#[derive(Debug)]
+pub struct Towels {
+    patterns: Vec<String>,
+    goals: Vec<String>,
+}
+fn viable_pattern_len_to_count(patterns: &[String], goal: &str) -> HashMap<usize, usize> {
+    let mut res = HashMap::new();
+
+    for pattern in patterns {
+        if goal.starts_with(pattern) {
+            let count = res.entry(pattern.len()).or_default();
+            *count += 1;
+        }
+    }
+
+    res
+}
+
+// memo is keyed by what's left of a goal, which is the same problem whichever goal it's from
+fn count_designs<'a>(patterns: &[String], goal: &'a str, memo: &mut Memo<&'a str, usize>) -> usize {
+    memo.get(goal, |count, goal| {
+        if goal.is_empty() {
+            return 1;
+        }
+
+        viable_pattern_len_to_count(patterns, goal)
+            .into_iter()
+            // recurse with a shorter goal using this pattern len
+            .map(|(len, num_patterns)| count(&goal[len..]) * num_patterns)
+            .sum()
+    })
+}
+
+fn parse_towels(text: &str) -> Result<Towels, Error> {
+    let mut sections = Sections::new(text);
+
+    let patterns = sections
+        .next_section()?
+        .parse_lines(|ln| parse_all(ln, comma_list(word)))?
+        .concat()
+        .into_iter()
+        .map(|s| s.to_owned())
+        .collect();
+    let goals = sections.next_lines(|ln| Ok(ln.to_owned()))?;
+    sections.finish()?;
+
+    Ok(Towels { patterns, goals })
+}
+
+pub struct Day19;
+
+impl tools::Solution for Day19 {
+    type Input = Towels;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Towels, Error> {
+        parse_towels(text)
+    }
+
+    fn part_one(towels: &Towels) -> Result<usize, Error> {
+        let mut memo = Memo::new();
+        let num_possible: usize = towels
+            .goals
+            .iter()
+            .filter(|goal| count_designs(&towels.patterns, goal, &mut memo) != 0)
+            .count();
+
+        Ok(num_possible)
+    }
+
+    fn part_two(towels: &Towels) -> Result<usize, Error> {
+        let mut memo = Memo::new();
+        let num_designs: usize = towels
+            .goals
+            .iter()
+            .map(|goal| count_designs(&towels.patterns, goal, &mut memo))
+            .sum();
+
+        Ok(num_designs)
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::{anyhow, bail, Error};
+use std::str::FromStr as _;
+use tools::{Cell, Direction, Grid, Point, Recorder, Recording, Settings, Stencil};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Wall,
+    Track,
+    Start,
+    End,
+}
+
+impl Cell for Tile {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Track),
+            'S' => Some(Tile::Start),
+            'E' => Some(Tile::End),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Wall => '#',
+            Tile::Track => '.',
+            Tile::Start => 'S',
+            Tile::End => 'E',
+        }
+    }
+}
+
+fn find_neighbors(grid: &Grid<Tile>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut result = Vec::new();
+
+    for dir in Direction::ALL {
+        let Some(new_pos) = grid.step(pos, dir) else {
+            continue;
+        };
+
+        if grid[new_pos] != Tile::Track {
+            continue;
+        }
+
+        result.push(new_pos);
+    }
+
+    result
+}
+
+// every position from start to end, inclusive
+fn find_path(
+    grid: &Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Result<Vec<(usize, usize)>, Error> {
+    let found = tools::bfs(start, |pos| find_neighbors(grid, *pos), |pos| *pos == end)
+        .ok_or_else(|| anyhow!("no path found"))?;
+
+    Ok(found.path)
+}
+
+// how many cheats of up to max_len steps save at least min_saved
+// a cheat is counted once per start and end position, however it gets there
+fn count_cheats(track: &Track, max_len: usize, min_saved: usize) -> Result<usize, Error> {
+    let Track {
+        grid, start, end, ..
+    } = track;
+
+    let from_start = tools::bfs_all(*start, |pos| find_neighbors(grid, *pos));
+    let to_end = tools::bfs_all(*end, |pos| find_neighbors(grid, *pos));
+
+    let time_clean = from_start
+        .cost(end)
+        .ok_or_else(|| anyhow!("no path found"))?;
+
+    let cheats = Stencil::manhattan(max_len);
+    let mut num_good_cheats = 0;
+
+    for (cheat_src, time_before) in &from_start.costs {
+        for cheat_dest in grid.around(*cheat_src, &cheats) {
+            let Some(time_after) = to_end.cost(&cheat_dest) else {
+                continue;
+            };
+
+            let cheat = Point::from(*cheat_src).vector_to(cheat_dest.into());
+            let time_dirty = time_before + cheat.manhattan() as usize + time_after;
+
+            if time_dirty + min_saved <= time_clean {
+                num_good_cheats += 1;
+            }
+        }
+    }
+
+    Ok(num_good_cheats)
+}
+
+pub struct Track {
+    grid: Grid<Tile>,
+    start: (usize, usize),
+    end: (usize, usize),
+    // the least time a cheat must save to count, for each part
+    min_saved: [usize; 2],
+}
+
+pub struct Day20;
+
+impl tools::Solution for Day20 {
+    type Input = Track;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Track, Error> {
+        Self::parse_with(text, &Settings::default())
+    }
+
+    // the full input counts cheats saving 100 picoseconds, the example's
+    // lists in the puzzle text go lower
+    fn parse_with(text: &str, settings: &Settings) -> Result<Track, Error> {
+        let min_saved = [
+            settings.get_or("min_saved_1", 100)?,
+            settings.get_or("min_saved_2", 100)?,
+        ];
+
+        let mut grid = Grid::from_str(text)?;
+
+        let start = grid
+            .find_unique(|tile| *tile == Tile::Start)
+            .map_err(|err| anyhow!("start pos: {err}"))?;
+        let end = grid
+            .find_unique(|tile| *tile == Tile::End)
+            .map_err(|err| anyhow!("end pos: {err}"))?;
+
+        grid[start] = Tile::Track;
+        grid[end] = Tile::Track;
+
+        Ok(Track {
+            grid,
+            start,
+            end,
+            min_saved,
+        })
+    }
+
+    fn part_one(track: &Track) -> Result<usize, Error> {
+        count_cheats(track, 2, track.min_saved[0])
+    }
+
+    fn part_two(track: &Track) -> Result<usize, Error> {
+        count_cheats(track, 20, track.min_saved[1])
+    }
+
+    // the path taken without cheating
+    fn record(track: &Track, part: u32) -> Result<Recording, Error> {
+        if part != 1 {
+            bail!("only part 1 is recorded");
+        }
+
+        let Track {
+            grid, start, end, ..
+        } = track;
+        let path = find_path(grid, *start, *end)?;
+        let mut rec = Recording::new(grid);
+
+        for (steps, pos) in path.iter().enumerate() {
+            rec.set(*pos, 'O');
+            rec.end_frame(|| format!("step {steps}"));
+        }
+
+        Ok(rec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tools::Solution as _;
+
+    // totals of the savings listed in the puzzle text
+    #[test]
+    fn sample_cheats() {
+        let track = Day20::parse(include_str!("../input01.txt")).unwrap();
+
+        assert_eq!(count_cheats(&track, 2, 2).unwrap(), 44);
+        assert_eq!(count_cheats(&track, 2, 20).unwrap(), 5);
+        assert_eq!(count_cheats(&track, 20, 50).unwrap(), 285);
+        assert_eq!(count_cheats(&track, 20, 76).unwrap(), 3);
+    }
+}
@@ -0,0 +1,172 @@
+use anyhow::{anyhow, Error};
+use rayon::prelude::*;
+use tools::parse::{parse_all, parse_lines, unsigned};
+
+fn mix(secret_num: usize, value: usize) -> usize {
+    // To mix a value into the secret number, calculate the bitwise XOR of the given value and the secret number. Then, the secret number becomes the result of that operation. (If the secret number is 42 and you were to mix 15 into the secret number, the secret number would become 37.)
+    secret_num ^ value
+}
+
+fn prune(secret_num: usize) -> usize {
+    // To prune the secret number, calculate the value of the secret number modulo 16777216. Then, the secret number becomes the result of that operation. (If the secret number is 100000000 and you were to prune the secret number, the secret number would become 16113920.)
+    secret_num % 16777216
+}
+
+fn next_secret_num(mut secret_num: usize) -> usize {
+    // Calculate the result of multiplying the secret number by 64. Then, mix this result into the secret number. Finally, prune the secret number.
+    secret_num = prune(mix(secret_num, secret_num * 64));
+    // Calculate the result of dividing the secret number by 32. Round the result down to the nearest integer. Then, mix this result into the secret number. Finally, prune the secret number.
+    secret_num = mix(secret_num, secret_num / 32);
+    secret_num = prune(secret_num);
+    // Calculate the result of multiplying the secret number by 2048. Then, mix this result into the secret number. Finally, prune the secret number.
+    secret_num = mix(secret_num, secret_num * 2048);
+    secret_num = prune(secret_num);
+    secret_num
+}
+
+#[cfg(test)]
+fn gen_many_vec(mut secret_num: usize, count: usize) -> Vec<usize> {
+    let mut res = Vec::with_capacity(count);
+
+    for _i in 0..count {
+        secret_num = next_secret_num(secret_num);
+        res.push(secret_num);
+    }
+
+    res
+}
+
+fn iter_change_sequences() -> impl Iterator<Item = [i64; 4]> {
+    // some of these aren't possible, but that's fine, evaling them is just a waste of time
+    tools::generate(|co| async move {
+        for c1 in -9..=9 {
+            for c2 in -9..=9 {
+                for c3 in -9..=9 {
+                    for c4 in -9..=9 {
+                        co.yield_([c1, c2, c3, c4]).await;
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn eval_change_sequence(expected_seq: [i64; 4], mut secret_num: usize) -> Option<i64> {
+    let mut actual_seq: [i64; 4] = [0, 0, 0, 0];
+    let mut begin_i = 0;
+    let mut end_i = 0;
+
+    for _i in 0..2000 {
+        let prev_price = i64::try_from(secret_num % 10).unwrap();
+        secret_num = next_secret_num(secret_num);
+        let this_price = i64::try_from(secret_num % 10).unwrap();
+        let price_delta = this_price - prev_price;
+
+        // pop delta
+        if end_i - begin_i >= 4 {
+            begin_i += 1;
+        }
+
+        // push_delta
+        actual_seq[end_i % 4] = price_delta;
+        end_i += 1;
+
+        // compare if we have 4 things to compare
+        if end_i - begin_i >= 4 {
+            let mut matched = true;
+            for j in 0..4 {
+                let actual_price = actual_seq[(begin_i + j) % 4];
+                let expected_price = expected_seq[j];
+                if actual_price != expected_price {
+                    matched = false;
+                    break;
+                }
+            }
+
+            if matched {
+                return Some(this_price);
+            }
+        }
+    }
+
+    None
+}
+
+fn eval_change_sequence_all(expected_seq: [i64; 4], buyers: &[usize]) -> i64 {
+    let mut tot = 0;
+
+    for buyer in buyers {
+        tot += eval_change_sequence(expected_seq, *buyer).unwrap_or_default();
+    }
+
+    tot
+}
+
+pub struct Day22;
+
+impl tools::Solution for Day22 {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Vec<usize>, Error> {
+        parse_lines(text, |ln| parse_all(ln, unsigned))
+    }
+
+    fn part_one(buyers: &Vec<usize>) -> Result<usize, Error> {
+        let mut sum = 0;
+
+        for buyer in buyers {
+            let mut secret_num = *buyer;
+
+            for _i in 0..2000 {
+                secret_num = next_secret_num(secret_num);
+            }
+
+            sum += secret_num;
+        }
+
+        Ok(sum)
+    }
+
+    fn part_two(buyers: &Vec<usize>) -> Result<i64, Error> {
+        let seauences: Vec<_> = iter_change_sequences().collect();
+        let val = seauences
+            .into_par_iter()
+            .map(|seq| eval_change_sequence_all(seq, buyers))
+            .max();
+
+        val.ok_or_else(|| anyhow!("no sequences"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the worked examples from the puzzle text
+    #[test]
+    fn secret_numbers() {
+        assert_eq!(prune(100000000), 16113920);
+        assert_eq!(mix(42, 15), 37);
+        assert_eq!(
+            gen_many_vec(123, 10),
+            &[
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254
+            ]
+        );
+    }
+
+    #[test]
+    fn change_sequences() {
+        assert_eq!(eval_change_sequence([-2, 1, -1, 3], 1), Some(7));
+        assert_eq!(eval_change_sequence([-2, 1, -1, 3], 2), Some(7));
+        assert_eq!(eval_change_sequence([-2, 1, -1, 3], 3), None);
+        assert_eq!(eval_change_sequence([-2, 1, -1, 3], 2024), Some(9));
+        assert_eq!(
+            eval_change_sequence_all([-2, 1, -1, 3], &[1, 2, 3, 2024]),
+            23
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::{anyhow, Error};
+use std::collections::{HashMap, HashSet};
+use tools::parse::{key_value, parse_all, parse_lines, word};
+use tools::UnionFind;
+
+#[derive(Debug)]
+pub struct Network {
+    host_names: Vec<String>,
+    adjacent: HashSet<(usize, usize)>,
+}
+
+fn get_key(mut host_a: usize, mut host_b: usize) -> (usize, usize) {
+    if host_a > host_b {
+        std::mem::swap(&mut host_a, &mut host_b);
+    }
+
+    (host_a, host_b)
+}
+
+impl Network {
+    fn len(&self) -> usize {
+        self.host_names.len()
+    }
+
+    fn is_adjacent(&self, host_a: usize, host_b: usize) -> bool {
+        self.adjacent.contains(&get_key(host_a, host_b))
+    }
+}
+
+fn get_host_id(host_ids: &mut HashMap<String, usize>, host: &str) -> usize {
+    match host_ids.get(host) {
+        Some(id) => *id,
+        None => {
+            let id = host_ids.len();
+            host_ids.insert(host.to_owned(), id);
+            id
+        }
+    }
+}
+
+// hosts linked through any number of hops share a group, named by its root host
+// a cluster never spans two groups, so only the host's own group needs scanning
+struct Groups {
+    root_of: Vec<usize>,
+    members: Vec<Vec<usize>>,
+}
+
+impl Groups {
+    fn new(network: &Network) -> Self {
+        let mut linked = UnionFind::new(network.len());
+        for (host_a, host_b) in &network.adjacent {
+            linked.union(*host_a, *host_b);
+        }
+
+        let root_of: Vec<usize> = (0..network.len())
+            .map(|host_id| linked.find(host_id))
+            .collect();
+
+        let mut members = vec![Vec::new(); network.len()];
+        for (host_id, root) in root_of.iter().enumerate() {
+            members[*root].push(host_id);
+        }
+
+        Self { root_of, members }
+    }
+
+    fn of(&self, host_id: usize) -> &[usize] {
+        &self.members[self.root_of[host_id]]
+    }
+}
+
+// every three hosts that are all linked to each other, where at least one name starts with t
+fn find_clusters(network: &Network) -> Vec<[usize; 3]> {
+    let mut clusters = Vec::new();
+    // look through all 3 tuples of hosts and add those that are interconnected
+    for host_a in 0..network.len() {
+        for host_b in host_a + 1..network.len() {
+            for host_c in host_b + 1..network.len() {
+                // filter out tuples that are not interconnected
+                if !network.is_adjacent(host_a, host_b)
+                    || !network.is_adjacent(host_b, host_c)
+                    || !network.is_adjacent(host_c, host_a)
+                {
+                    continue;
+                }
+
+                // filter out tuples where none of the hosts starts with t
+                if !network.host_names[host_a].starts_with('t')
+                    && !network.host_names[host_b].starts_with('t')
+                    && !network.host_names[host_c].starts_with('t')
+                {
+                    continue;
+                }
+
+                // these will always be in order
+                clusters.push([host_a, host_b, host_c]);
+            }
+        }
+    }
+
+    clusters
+}
+
+fn find_cluster(host_id: usize, group: &[usize], network: &Network) -> HashSet<usize> {
+    let mut cluster = HashSet::new();
+
+    cluster.insert(host_id);
+
+    loop {
+        let mut changed = false;
+
+        for &host_id_2 in group {
+            if cluster
+                .iter()
+                .all(|host_id| network.is_adjacent(host_id_2, *host_id))
+            {
+                cluster.insert(host_id_2);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    cluster
+}
+
+fn read_network(text: &str) -> Result<Network, Error> {
+    let mut host_ids = HashMap::new();
+
+    // keyed by host id
+    let mut adjacent = HashSet::new();
+
+    // "kh-tc"
+    let links = parse_lines(text, |ln| parse_all(ln, key_value(word, "-", word)))?;
+
+    for (host_a, host_b) in links {
+        let host_a = get_host_id(&mut host_ids, host_a);
+        let host_b = get_host_id(&mut host_ids, host_b);
+
+        adjacent.insert(get_key(host_a, host_b));
+    }
+
+    // convert host_ids to host_names
+    let mut host_names = Vec::new();
+    host_names.resize_with(host_ids.len(), String::default);
+
+    for (name, id) in host_ids.into_iter() {
+        host_names[id] = name;
+    }
+
+    Ok(Network {
+        host_names,
+        adjacent,
+    })
+}
+
+pub struct Day23;
+
+impl tools::Solution for Day23 {
+    type Input = Network;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(text: &str) -> Result<Network, Error> {
+        read_network(text)
+    }
+
+    fn part_one(network: &Network) -> Result<usize, Error> {
+        Ok(find_clusters(network).len())
+    }
+
+    fn part_two(network: &Network) -> Result<String, Error> {
+        let groups = Groups::new(network);
+        let cluster = (0..network.len())
+            .map(|host_id| find_cluster(host_id, groups.of(host_id), network))
+            .max_by_key(|cluster| cluster.len())
+            .ok_or_else(|| anyhow!("empty network"))?;
+
+        let mut names: Vec<&str> = cluster
+            .iter()
+            .map(|host_id| network.host_names[*host_id].as_str())
+            .collect();
+        names.sort();
+
+        Ok(names.join(","))
+    }
+}
@@ -1,8 +1,13 @@
-use anyhow::{anyhow, bail, Error};
+use anyhow::{bail, Error};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::value;
+use nom::IResult;
 use std::collections::BTreeMap;
 use std::fmt::Display;
-use std::io::BufRead;
 use std::marker::PhantomData;
+use tools::parse::{gate, parse_all};
+use tools::Sections;
 
 #[derive(Clone, Copy, Debug)]
 pub enum Operator {
@@ -74,15 +79,6 @@ impl<'a> GateRef<'a> {
 }
 
 impl<'a> WireRef<'a> {
-    #[allow(unused)]
-    pub fn gate(self) -> Option<GateRef<'a>> {
-        let gate_id = self.circuit.wire_to_gate[self.wire_id]?;
-        Some(GateRef {
-            circuit: &self.circuit,
-            gate_id: gate_id,
-        })
-    }
-
     pub fn has_class(self, expected: char) -> bool {
         let Some(actual) = self.circuit.wire_to_name[self.wire_id].chars().next() else {
             return false;
@@ -154,28 +150,16 @@ pub struct Circuit {
 }
 
 impl Circuit {
-    pub fn read(rd: impl BufRead) -> Result<Self, Error> {
+    pub fn parse(text: &str) -> Result<Self, Error> {
         let mut wire_ids = BTreeMap::new();
+        let mut sections = Sections::new(text);
 
-        let mut initial_values = BTreeMap::new();
-        let mut gates = Vec::new();
-        let mut in_gates = false;
-
-        for ln in rd.lines() {
-            let ln = ln?;
-
-            if ln.is_empty() {
-                in_gates = true;
-                continue;
-            }
-
-            if in_gates {
-                gates.push(parse_gate(&ln, &mut wire_ids)?);
-            } else {
-                let (k, v) = parse_initial_value(&ln, &mut wire_ids)?;
-                initial_values.insert(k, v);
-            }
-        }
+        let initial_values: BTreeMap<usize, bool> = sections
+            .next_pairs(": ", |k, v| parse_initial_value(k, v, &mut wire_ids))?
+            .into_iter()
+            .collect();
+        let gates = sections.next_lines(|ln| parse_gate(ln, &mut wire_ids))?;
+        sections.finish()?;
 
         let mut initial_state = vec![None; wire_ids.len()];
         for (id, val) in &initial_values {
@@ -199,8 +183,8 @@ impl Circuit {
                 continue;
             }
             let mut found = false;
-            for gate_id in 0..gates.len() {
-                if gates[gate_id].output_wire_id == wire_id {
+            for (gate_id, gate) in gates.iter().enumerate() {
+                if gate.output_wire_id == wire_id {
                     wire_to_gate[wire_id] = Some(gate_id);
                     found = true;
                     break;
@@ -250,87 +234,71 @@ impl Circuit {
         })
     }
 
-    pub fn initial_state(&self) -> CircuitState {
+    pub fn initial_state(&self) -> CircuitState<'_> {
         CircuitState {
             state: self.initial_state.clone(),
             _circuit: PhantomData,
         }
     }
 
-    #[allow(unused)]
-    pub fn empty_state(&self) -> CircuitState {
-        CircuitState {
-            state: vec![None; self.wire_to_name.len()],
-            _circuit: PhantomData,
-        }
-    }
-
-    pub fn iter_gates(&self) -> impl Iterator<Item = GateRef> {
-        tools::iter_coro(
-            #[coroutine]
-            || {
-                for gate_id in &self.gate_order {
-                    yield GateRef {
-                        circuit: self,
-                        gate_id: *gate_id,
-                    };
-                }
-            },
-        )
+    pub fn iter_gates(&self) -> impl Iterator<Item = GateRef<'_>> {
+        tools::generate(|co| async move {
+            for gate_id in &self.gate_order {
+                co.yield_(GateRef {
+                    circuit: self,
+                    gate_id: *gate_id,
+                })
+                .await;
+            }
+        })
     }
 
-    pub fn iter_wires(&self) -> impl Iterator<Item = WireRef> {
-        tools::iter_coro(
-            #[coroutine]
-            || {
-                for wire_id in 0..self.wire_to_name.len() {
-                    yield WireRef {
-                        circuit: self,
-                        wire_id,
-                    };
-                }
-            },
-        )
+    pub fn iter_wires(&self) -> impl Iterator<Item = WireRef<'_>> {
+        tools::generate(|co| async move {
+            for wire_id in 0..self.wire_to_name.len() {
+                co.yield_(WireRef {
+                    circuit: self,
+                    wire_id,
+                })
+                .await;
+            }
+        })
     }
 
-    pub fn iter_bus_lsb(&self, bus: Bus) -> impl Iterator<Item = WireRef> {
-        tools::iter_coro(
-            #[coroutine]
-            move || {
-                let bus = match bus {
-                    Bus::X => &self.x_bus,
-                    Bus::Y => &self.y_bus,
-                    Bus::Z => &self.z_bus,
-                };
-
-                for wire_id in bus.iter() {
-                    yield WireRef {
-                        circuit: self,
-                        wire_id: *wire_id,
-                    };
-                }
-            },
-        )
+    pub fn iter_bus_lsb(&self, bus: Bus) -> impl Iterator<Item = WireRef<'_>> {
+        tools::generate(move |co| async move {
+            let bus = match bus {
+                Bus::X => &self.x_bus,
+                Bus::Y => &self.y_bus,
+                Bus::Z => &self.z_bus,
+            };
+
+            for wire_id in bus.iter() {
+                co.yield_(WireRef {
+                    circuit: self,
+                    wire_id: *wire_id,
+                })
+                .await;
+            }
+        })
     }
 
-    pub fn iter_bus_msb(&self, bus: Bus) -> impl Iterator<Item = WireRef> {
-        tools::iter_coro(
-            #[coroutine]
-            move || {
-                let bus = match bus {
-                    Bus::X => &self.x_bus,
-                    Bus::Y => &self.y_bus,
-                    Bus::Z => &self.z_bus,
-                };
-
-                for wire_id in bus.iter().rev() {
-                    yield WireRef {
-                        circuit: self,
-                        wire_id: *wire_id,
-                    };
-                }
-            },
-        )
+    pub fn iter_bus_msb(&self, bus: Bus) -> impl Iterator<Item = WireRef<'_>> {
+        tools::generate(move |co| async move {
+            let bus = match bus {
+                Bus::X => &self.x_bus,
+                Bus::Y => &self.y_bus,
+                Bus::Z => &self.z_bus,
+            };
+
+            for wire_id in bus.iter().rev() {
+                co.yield_(WireRef {
+                    circuit: self,
+                    wire_id: *wire_id,
+                })
+                .await;
+            }
+        })
     }
 
     pub fn len(&self) -> usize {
@@ -342,7 +310,7 @@ impl Circuit {
         let wire_i = self.gates[gate_i].output_wire_id;
         let wire_j = self.gates[gate_j].output_wire_id;
         self.wire_to_gate.swap(wire_i, wire_j);
-        let [gate_i, gate_j] = self.gates.get_many_mut([gate_i, gate_j]).unwrap();
+        let [gate_i, gate_j] = self.gates.get_disjoint_mut([gate_i, gate_j]).unwrap();
         std::mem::swap(&mut gate_i.output_wire_id, &mut gate_j.output_wire_id);
     }
 }
@@ -358,43 +326,30 @@ fn get_wire_id(wire_ids: &mut BTreeMap<String, usize>, name: &str) -> usize {
     }
 }
 
-fn parse_gate(s: &str, wire_ids: &mut BTreeMap<String, usize>) -> Result<Gate, Error> {
-    let tokens: Vec<_> = s.split_ascii_whitespace().collect();
-
-    if tokens.len() != 5 {
-        bail!("gate not enough tokens")
-    }
-
-    let left_input = get_wire_id(wire_ids, tokens[0]);
-    let operator = match tokens[1] {
-        "AND" => Operator::And,
-        "OR" => Operator::Or,
-        "XOR" => Operator::Xor,
-        _ => bail!("gate invalid operator"),
-    };
+fn parse_operator(input: &str) -> IResult<&str, Operator> {
+    alt((
+        value(Operator::And, tag("AND")),
+        value(Operator::Or, tag("OR")),
+        value(Operator::Xor, tag("XOR")),
+    ))(input)
+}
 
-    let right_input = get_wire_id(wire_ids, tokens[2]);
-    if tokens[3] != "->" {
-        bail!("gate missing arrow");
-    }
+fn parse_gate(s: &str, wire_ids: &mut BTreeMap<String, usize>) -> Result<Gate, Error> {
+    let (left_input, operator, right_input, output) = parse_all(s, gate(parse_operator))?;
 
-    let output = get_wire_id(wire_ids, tokens[4]);
     Ok(Gate {
-        left_wire_id: left_input,
+        left_wire_id: get_wire_id(wire_ids, left_input),
         operator,
-        right_wire_id: right_input,
-        output_wire_id: output,
+        right_wire_id: get_wire_id(wire_ids, right_input),
+        output_wire_id: get_wire_id(wire_ids, output),
     })
 }
 
 fn parse_initial_value(
-    s: &str,
+    k: &str,
+    v: &str,
     wire_ids: &mut BTreeMap<String, usize>,
 ) -> Result<(usize, bool), Error> {
-    let (k, v) = s
-        .split_once(": ")
-        .ok_or_else(|| anyhow!("bad initial value"))?;
-
     let k = get_wire_id(wire_ids, k);
 
     let v = match v {
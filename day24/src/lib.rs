@@ -0,0 +1,71 @@
+use crate::circuit::{Bus, Circuit, CircuitState, GateRef, Operator};
+use anyhow::{anyhow, bail, Error};
+
+mod circuit;
+
+fn propagate(state: &CircuitState, gate: GateRef) -> Option<bool> {
+    let left = state.get(gate.left_input())?;
+    let right = state.get(gate.right_input())?;
+    let val = match gate.operator() {
+        Operator::And => left && right,
+        Operator::Or => left || right,
+        Operator::Xor => left ^ right,
+    };
+
+    Some(val)
+}
+
+fn solve_circuit_fast(circuit: &Circuit, state: &mut CircuitState) {
+    for gate in circuit.iter_gates() {
+        let Some(val) = propagate(state, gate) else {
+            return;
+        };
+
+        state.set(gate.output(), val);
+    }
+}
+
+fn get_value(
+    circuit: &Circuit,
+    state: &CircuitState,
+    bus: Bus,
+    mut num_bits: usize,
+) -> Option<usize> {
+    let mut value = 0;
+
+    for wire in circuit.iter_bus_msb(bus) {
+        if num_bits == 0 {
+            break;
+        }
+
+        let val = state.get(wire)?;
+
+        let val = usize::from(val);
+        value = value * 2 + val;
+        num_bits -= 1
+    }
+
+    Some(value)
+}
+
+pub struct Day24;
+
+impl tools::Solution for Day24 {
+    type Input = Circuit;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(text: &str) -> Result<Circuit, Error> {
+        Circuit::parse(text)
+    }
+
+    fn part_one(circuit: &Circuit) -> Result<usize, Error> {
+        let mut wire_values = circuit.initial_state();
+        solve_circuit_fast(circuit, &mut wire_values);
+        get_value(circuit, &wire_values, Bus::Z, 46).ok_or_else(|| anyhow!("z not solved"))
+    }
+
+    fn part_two(_circuit: &Circuit) -> Result<String, Error> {
+        bail!("not solved yet");
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{anyhow, bail, Error};
+use tools::{ParseError, Sections};
+
+#[derive(Debug, PartialEq)]
+pub struct KeyLock {
+    is_lock: bool,
+    heights: [u8; 5],
+}
+
+type KeyRow = [bool; 5];
+type KeyGrid = [KeyRow; 7];
+
+fn row_all_same(row: &KeyRow) -> bool {
+    tools::array_windows(row).all(|[a, b]| *a == *b)
+}
+
+fn parse_keylock(rows: &KeyGrid) -> Result<KeyLock, Error> {
+    if !row_all_same(&rows[0]) {
+        bail!("bad first row");
+    }
+
+    if !row_all_same(&rows[rows.len() - 1]) {
+        bail!("bad last row");
+    }
+
+    let is_lock = rows[0][0];
+    let is_key = rows[rows.len() - 1][0];
+
+    let (is_lock, heights) = match (is_lock, is_key) {
+        (true, false) => {
+            // look down for locks
+            let mut heights: [u8; 5] = [0, 0, 0, 0, 0];
+            for col in 0..5 {
+                for row in &rows[1..7] {
+                    if !row[col] {
+                        break;
+                    }
+                    heights[col] += 1;
+                }
+            }
+            (true, heights)
+        }
+        (false, true) => {
+            // look up for keys
+            let mut heights: [u8; 5] = [0, 0, 0, 0, 0];
+
+            for col in 0..5 {
+                for row in rows[0..6].iter().rev() {
+                    if !row[col] {
+                        break;
+                    }
+                    heights[col] += 1;
+                }
+            }
+            (false, heights)
+        }
+        _ => {
+            bail!("invalid keylock first/last row");
+        }
+    };
+
+    Ok(KeyLock { is_lock, heights })
+}
+
+fn parse_row(ln: &str) -> Result<KeyRow, Error> {
+    let row: Vec<bool> = ln
+        .chars()
+        .enumerate()
+        .map(|(idx, ch)| match ch {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new(format!("invalid keylock char {ch:?}")).with_col(idx + 1)),
+        })
+        .collect::<Result<_, _>>()?;
+
+    row.try_into().map_err(|_| anyhow!("bad row length"))
+}
+
+fn parse_keylocks(text: &str) -> Result<Vec<KeyLock>, Error> {
+    Sections::new(text).parse_rest(|section| {
+        let rows = section.parse_lines(parse_row)?;
+        let rows: KeyGrid = rows.try_into().map_err(|_| anyhow!("bad row count"))?;
+        parse_keylock(&rows)
+    })
+}
+
+fn fits(a: &KeyLock, b: &KeyLock) -> bool {
+    if !(a.is_lock ^ b.is_lock) {
+        return false;
+    }
+
+    a.heights
+        .iter()
+        .zip(b.heights.iter())
+        .all(|(a, b)| a + b <= 5)
+}
+
+pub struct Day25;
+
+impl tools::Solution for Day25 {
+    type Input = Vec<KeyLock>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<KeyLock>, Error> {
+        parse_keylocks(text)
+    }
+
+    fn part_one(key_locks: &Vec<KeyLock>) -> Result<usize, Error> {
+        let mut num_fit = 0;
+
+        for i in 0..key_locks.len() {
+            for j in i + 1..key_locks.len() {
+                if fits(&key_locks[i], &key_locks[j]) || fits(&key_locks[j], &key_locks[i]) {
+                    num_fit += 1;
+                }
+            }
+        }
+
+        Ok(num_fit)
+    }
+
+    fn part_two(_key_locks: &Vec<KeyLock>) -> Result<usize, Error> {
+        bail!("day 25 has no part two");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_KEY_LOCKS: [KeyLock; 5] = [
+        KeyLock {
+            is_lock: true,
+            heights: [0, 5, 3, 4, 3],
+        },
+        KeyLock {
+            is_lock: true,
+            heights: [1, 2, 0, 5, 3],
+        },
+        KeyLock {
+            is_lock: false,
+            heights: [5, 0, 2, 1, 3],
+        },
+        KeyLock {
+            is_lock: false,
+            heights: [4, 3, 4, 0, 2],
+        },
+        KeyLock {
+            is_lock: false,
+            heights: [3, 0, 2, 0, 1],
+        },
+    ];
+
+    #[test]
+    fn parse_sample() {
+        let key_locks = parse_keylocks(include_str!("../input01.txt")).unwrap();
+        assert_eq!(key_locks, SAMPLE_KEY_LOCKS);
+    }
+}
@@ -0,0 +1,297 @@
+use crate::settings::Settings;
+use crate::solution::{Part, Solution};
+use anyhow::{anyhow, bail, Error};
+use std::fmt::Display;
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+// the times of one stage over repeated runs, fastest first
+pub struct Samples {
+    times: Vec<Duration>,
+}
+
+impl Samples {
+    fn new(mut times: Vec<Duration>) -> Self {
+        assert!(!times.is_empty());
+        times.sort();
+        Self { times }
+    }
+
+    pub fn runs(&self) -> usize {
+        self.times.len()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mid = self.times.len() / 2;
+        if self.times.len().is_multiple_of(2) {
+            (self.times[mid - 1] + self.times[mid]) / 2
+        } else {
+            self.times[mid]
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.times[0]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.times[self.times.len() - 1]
+    }
+}
+
+// like Answers, a part that wasn't asked for is None
+pub struct Timings {
+    pub parse: Samples,
+    pub part_one: Option<Result<Samples, Error>>,
+    pub part_two: Option<Result<Samples, Error>>,
+}
+
+impl Timings {
+    pub fn stages(&self) -> impl Iterator<Item = (Stage, Result<&Samples, &Error>)> {
+        let parts = [
+            (Stage::PartOne, &self.part_one),
+            (Stage::PartTwo, &self.part_two),
+        ];
+
+        std::iter::once((Stage::Parse, Ok(&self.parse))).chain(
+            parts
+                .into_iter()
+                .filter_map(|(stage, samples)| Some((stage, samples.as_ref()?.as_ref()))),
+        )
+    }
+}
+
+fn time_runs<T>(runs: usize, mut f: impl FnMut() -> Result<T, Error>) -> Result<Samples, Error> {
+    let mut times = Vec::with_capacity(runs);
+
+    for _i in 0..runs {
+        let start = Instant::now();
+        let out = black_box(f()?);
+        times.push(start.elapsed());
+        drop(out);
+    }
+
+    Ok(Samples::new(times))
+}
+
+// times parsing and each selected part separately, `runs` times each
+pub fn bench<S: Solution>(
+    text: &str,
+    settings: &Settings,
+    part: Part,
+    runs: usize,
+) -> Result<Timings, Error> {
+    if runs == 0 {
+        bail!("need at least one run");
+    }
+
+    let parse = time_runs(runs, || S::parse_with(black_box(text), settings))?;
+    let input = S::parse_with(text, settings)?;
+
+    let part_one = part
+        .includes_one()
+        .then(|| time_runs(runs, || S::part_one(black_box(&input))));
+
+    let part_two = part
+        .includes_two()
+        .then(|| time_runs(runs, || S::part_two(black_box(&input))));
+
+    Ok(Timings {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl FromStr for Stage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part_1" => Ok(Stage::PartOne),
+            "part_2" => Ok(Stage::PartTwo),
+            _ => bail!("invalid stage {s:?}"),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part_1",
+            Stage::PartTwo => "part_2",
+        };
+
+        f.write_str(s)
+    }
+}
+
+// one line of a results file, times in nanoseconds:
+//   <day> <input> <stage> <runs> <median> <min> <max>
+// e.g. `6 input02.txt part_2 10 812345678 801234567 830123456`
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Record {
+    pub day: u32,
+    pub input: String,
+    pub stage: Stage,
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Record {
+    pub fn new(day: u32, input: &str, stage: Stage, samples: &Samples) -> Self {
+        Self {
+            day,
+            input: input.to_owned(),
+            stage,
+            runs: samples.runs(),
+            median: samples.median(),
+            min: samples.min(),
+            max: samples.max(),
+        }
+    }
+
+    // blank lines and lines starting with # are ignored
+    pub fn parse_all(text: &str) -> Result<Vec<Self>, Error> {
+        let mut records = Vec::new();
+
+        for (line_num, ln) in text.lines().enumerate() {
+            let ln = ln.trim();
+            if ln.is_empty() || ln.starts_with('#') {
+                continue;
+            }
+
+            let record =
+                Self::from_str(ln).map_err(|err| anyhow!("line {}: {err}", line_num + 1))?;
+            records.push(record);
+        }
+
+        Ok(records)
+    }
+}
+
+impl FromStr for Record {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+        let [day, input, stage, runs, median, min, max] = tokens[..] else {
+            bail!("expected 7 fields, got {}", tokens.len());
+        };
+
+        let nanos =
+            |s: &str| -> Result<Duration, Error> { Ok(Duration::from_nanos(u64::from_str(s)?)) };
+
+        Ok(Record {
+            day: u32::from_str(day)?,
+            input: input.to_owned(),
+            stage: Stage::from_str(stage)?,
+            runs: usize::from_str(runs)?,
+            median: nanos(median)?,
+            min: nanos(min)?,
+            max: nanos(max)?,
+        })
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {}",
+            self.day,
+            self.input,
+            self.stage,
+            self.runs,
+            self.median.as_nanos(),
+            self.min.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(millis: &[u64]) -> Samples {
+        Samples::new(millis.iter().copied().map(Duration::from_millis).collect())
+    }
+
+    #[test]
+    fn medians() {
+        let odd = samples(&[9, 1, 5]);
+        assert_eq!(odd.median(), Duration::from_millis(5));
+        assert_eq!(
+            (odd.min(), odd.max()),
+            (Duration::from_millis(1), Duration::from_millis(9))
+        );
+
+        assert_eq!(samples(&[8, 2, 4, 6]).median(), Duration::from_millis(5));
+        assert_eq!(samples(&[7]).median(), Duration::from_millis(7));
+    }
+
+    #[test]
+    fn record_lines() {
+        let line = "6 input02.txt part_2 10 812345678 801234567 830123456";
+        let record = Record::from_str(line).unwrap();
+        assert_eq!(
+            record,
+            Record {
+                day: 6,
+                input: "input02.txt".to_owned(),
+                stage: Stage::PartTwo,
+                runs: 10,
+                median: Duration::from_nanos(812345678),
+                min: Duration::from_nanos(801234567),
+                max: Duration::from_nanos(830123456),
+            }
+        );
+        assert_eq!(record.to_string(), line);
+
+        let from_samples = Record::new(1, "input01.txt", Stage::Parse, &samples(&[3, 1]));
+        assert_eq!(
+            from_samples.to_string(),
+            "1 input01.txt parse 2 2000000 1000000 3000000"
+        );
+
+        let records = Record::parse_all(&format!("# results\n\n{line}\n{from_samples}\n")).unwrap();
+        assert_eq!(records, [record, from_samples]);
+    }
+
+    #[test]
+    fn malformed_record_lines() {
+        for (bad, message) in [
+            ("6 input02.txt part_3 1 1 1 1", "invalid stage \"part_3\""),
+            ("6 input02.txt part_2 10 8123", "expected 7 fields, got 5"),
+            ("6 input02.txt part_2 1 1 1 1 1", "expected 7 fields, got 8"),
+            ("", "expected 7 fields, got 0"),
+            ("x a.txt parse 1 1 1 1", "invalid digit found in string"),
+            ("6 a.txt parse 1 1 -1 1", "invalid digit found in string"),
+        ] {
+            let err = Record::from_str(bad).unwrap_err();
+            assert_eq!(err.to_string(), message, "parsing {bad:?}");
+        }
+
+        // errors from a results file give the line they came from
+        let err = Record::parse_all("# results\n\n6 input02.txt parse 1 1 1").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: expected 7 fields, got 6");
+
+        // extra whitespace doesn't survive a round trip
+        let record = Record::from_str("  1  input01.txt parse 2 2 1 3 ").unwrap();
+        assert_eq!(record.to_string(), "1 input01.txt parse 2 2 1 3");
+        assert_eq!(Record::from_str(&record.to_string()).unwrap(), record);
+    }
+}
@@ -0,0 +1,314 @@
+use crate::geom::{Direction, Point};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+// a fixed number of bits, packed 64 to a word
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Bits {
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+        }
+    }
+
+    fn get(&self, idx: usize) -> bool {
+        self.words[idx / WORD_BITS] & (1 << (idx % WORD_BITS)) != 0
+    }
+
+    // true if it wasn't already set
+    fn insert(&mut self, idx: usize) -> bool {
+        let word = &mut self.words[idx / WORD_BITS];
+        let bit = 1 << (idx % WORD_BITS);
+        let added = *word & bit == 0;
+        *word |= bit;
+        added
+    }
+
+    // true if it was set
+    fn remove(&mut self, idx: usize) -> bool {
+        let word = &mut self.words[idx / WORD_BITS];
+        let bit = 1 << (idx % WORD_BITS);
+        let removed = *word & bit != 0;
+        *word &= !bit;
+        removed
+    }
+
+    // keeps the allocation
+    fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    fn union_with(&mut self, other: &Bits) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    fn intersect_with(&mut self, other: &Bits) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= other;
+        }
+    }
+
+    // indices of the set bits, in order
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(word_idx, word)| {
+            let mut word = *word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(word_idx * WORD_BITS + bit)
+            })
+        })
+    }
+}
+
+// a bool per cell of a grid, like a Grid<bool> in an eighth of the space
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BitGrid {
+    dim: (usize, usize),
+    bits: Bits,
+}
+
+impl BitGrid {
+    // every cell unset
+    pub fn new(dim: (usize, usize)) -> Self {
+        Self {
+            dim,
+            bits: Bits::new(dim.0 * dim.1),
+        }
+    }
+
+    pub fn dim(&self) -> (usize, usize) {
+        self.dim
+    }
+
+    pub fn in_bounds(&self, pos: (usize, usize)) -> bool {
+        pos.0 < self.dim.0 && pos.1 < self.dim.1
+    }
+
+    // one step from pos, if that's still on the grid
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        Some(Point::from(pos).step(dir, self.dim)?.into())
+    }
+
+    fn index(&self, pos: (usize, usize)) -> usize {
+        assert!(self.in_bounds(pos), "{pos:?} is off a {:?} grid", self.dim);
+        pos.0 * self.dim.1 + pos.1
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> bool {
+        self.bits.get(self.index(pos))
+    }
+
+    // true if it wasn't already set
+    pub fn insert(&mut self, pos: (usize, usize)) -> bool {
+        let idx = self.index(pos);
+        self.bits.insert(idx)
+    }
+
+    // true if it was set
+    pub fn remove(&mut self, pos: (usize, usize)) -> bool {
+        let idx = self.index(pos);
+        self.bits.remove(idx)
+    }
+
+    pub fn set(&mut self, pos: (usize, usize), val: bool) {
+        if val {
+            self.insert(pos);
+        } else {
+            self.remove(pos);
+        }
+    }
+
+    // unsets every cell, keeping the allocation for the next go
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    // how many cells are set
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    // cells set in either, panics if the grids are different sizes
+    pub fn union_with(&mut self, other: &BitGrid) {
+        assert_eq!(self.dim, other.dim, "grids differ in size");
+        self.bits.union_with(&other.bits);
+    }
+
+    // cells set in both, panics if the grids are different sizes
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        assert_eq!(self.dim, other.dim, "grids differ in size");
+        self.bits.intersect_with(&other.bits);
+    }
+
+    // positions of the set cells, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let num_cols = self.dim.1;
+        self.bits
+            .iter()
+            .map(move |idx| (idx / num_cols, idx % num_cols))
+    }
+}
+
+// a set of (position, direction) pairs on a grid, for walks that loop
+// once they're somewhere they've already been facing the same way
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DirSet {
+    dim: (usize, usize),
+    bits: Bits,
+}
+
+impl DirSet {
+    pub fn new(dim: (usize, usize)) -> Self {
+        Self {
+            dim,
+            bits: Bits::new(dim.0 * dim.1 * Direction::ALL.len()),
+        }
+    }
+
+    pub fn dim(&self) -> (usize, usize) {
+        self.dim
+    }
+
+    fn index(&self, pos: (usize, usize), dir: Direction) -> usize {
+        assert!(
+            pos.0 < self.dim.0 && pos.1 < self.dim.1,
+            "{pos:?} is off a {:?} grid",
+            self.dim
+        );
+        (pos.0 * self.dim.1 + pos.1) * Direction::ALL.len() + dir.index()
+    }
+
+    pub fn contains(&self, pos: (usize, usize), dir: Direction) -> bool {
+        self.bits.get(self.index(pos, dir))
+    }
+
+    // true if it wasn't already there
+    pub fn insert(&mut self, pos: (usize, usize), dir: Direction) -> bool {
+        let idx = self.index(pos, dir);
+        self.bits.insert(idx)
+    }
+
+    // true if it was there
+    pub fn remove(&mut self, pos: (usize, usize), dir: Direction) -> bool {
+        let idx = self.index(pos, dir);
+        self.bits.remove(idx)
+    }
+
+    // empties the set, keeping the allocation for the next go
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn count(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    // panics if the sets are for different sized grids
+    pub fn union_with(&mut self, other: &DirSet) {
+        assert_eq!(self.dim, other.dim, "grids differ in size");
+        self.bits.union_with(&other.bits);
+    }
+
+    // panics if the sets are for different sized grids
+    pub fn intersect_with(&mut self, other: &DirSet) {
+        assert_eq!(self.dim, other.dim, "grids differ in size");
+        self.bits.intersect_with(&other.bits);
+    }
+
+    // the positions with any direction set
+    pub fn positions(&self) -> BitGrid {
+        let mut positions = BitGrid::new(self.dim);
+        for idx in self.bits.iter() {
+            let cell = idx / Direction::ALL.len();
+            positions.insert((cell / self.dim.1, cell % self.dim.1));
+        }
+
+        positions
+    }
+
+    // the (position, direction) pairs in the set, in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), Direction)> + '_ {
+        let num_cols = self.dim.1;
+        self.bits.iter().map(move |idx| {
+            let cell = idx / Direction::ALL.len();
+            let dir = Direction::ALL[idx % Direction::ALL.len()];
+            ((cell / num_cols, cell % num_cols), dir)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_sets() {
+        // big enough to spill over a word
+        let mut a = BitGrid::new((9, 9));
+        assert!(a.insert((0, 1)));
+        assert!(!a.insert((0, 1)));
+        a.set((8, 8), true);
+        a.set((4, 4), true);
+
+        let mut b = BitGrid::new((9, 9));
+        b.insert((4, 4));
+        b.insert((7, 0));
+
+        let mut both = a.clone();
+        both.intersect_with(&b);
+        assert_eq!(both.iter().collect::<Vec<_>>(), [(4, 4)]);
+
+        a.union_with(&b);
+        assert_eq!(a.count(), 4);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [(0, 1), (4, 4), (7, 0), (8, 8)]
+        );
+        assert!(a.remove((8, 8)));
+        assert!(!a.get((8, 8)));
+
+        a.clear();
+        assert!(a.is_empty());
+
+        let mut seen = DirSet::new((9, 9));
+        assert!(seen.insert((8, 8), Direction::Left));
+        assert!(seen.insert((8, 8), Direction::Up));
+        assert!(!seen.insert((8, 8), Direction::Left));
+        assert!(!seen.contains((8, 8), Direction::Down));
+        assert_eq!(seen.count(), 2);
+        assert_eq!(
+            seen.iter().collect::<Vec<_>>(),
+            [((8, 8), Direction::Up), ((8, 8), Direction::Left)]
+        );
+        assert_eq!(seen.positions().iter().collect::<Vec<_>>(), [(8, 8)]);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::geom::{Direction, Vector};
+use crate::grid::Grid;
+
+// disjoint sets over 0..len, with union by rank and path compression
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    num_sets: usize,
+}
+
+impl UnionFind {
+    // every item in a set of its own
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            num_sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    // the representative of item's set
+    pub fn find(&mut self, item: usize) -> usize {
+        let mut root = item;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point everything on the way straight at the root
+        let mut cur = item;
+        while self.parent[cur] != root {
+            let next = self.parent[cur];
+            self.parent[cur] = root;
+            cur = next;
+        }
+
+        root
+    }
+
+    // true if they were in different sets
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.rank[a] < self.rank[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.num_sets -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // how many items are in item's set
+    pub fn set_size(&mut self, item: usize) -> usize {
+        let root = self.find(item);
+        self.size[root]
+    }
+
+    pub fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ComponentStats {
+    // number of cells
+    pub size: usize,
+    // the first cell in row-major order
+    pub first: (usize, usize),
+    // inclusive corners of the bounding box
+    pub min: (usize, usize),
+    pub max: (usize, usize),
+}
+
+// a grid split into connected components
+pub struct Components {
+    // component of each cell, numbered in row-major order of their first cells
+    pub labels: Grid<usize>,
+    // indexed by label
+    pub stats: Vec<ComponentStats>,
+}
+
+impl Components {
+    pub fn len(&self) -> usize {
+        self.stats.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stats.is_empty()
+    }
+
+    pub fn label(&self, pos: (usize, usize)) -> usize {
+        self.labels[pos]
+    }
+
+    // the shape of every component, indexed by label
+    pub fn geometry(&self) -> Vec<RegionGeometry> {
+        let mut geometry: Vec<RegionGeometry> = self
+            .stats
+            .iter()
+            .map(|stats| RegionGeometry {
+                area: stats.size,
+                perimeter: 0,
+                sides: 0,
+                min: stats.min,
+                max: stats.max,
+            })
+            .collect();
+
+        // neighbours follow the grid's topology: off a bounded grid counts as another region,
+        // on a torus a region carries on across the seam and has no edge there
+        let in_region = |label: usize, pos: (usize, usize), delta: Vector| {
+            self.labels
+                .offset(pos, delta)
+                .is_some_and(|next| self.labels[next] == label)
+        };
+
+        for (pos, label) in self.labels.iter() {
+            let region = &mut geometry[*label];
+
+            for dir in Direction::ALL {
+                let side = dir.delta();
+                // the next direction round, so each corner of the cell is looked at once
+                let other_side = dir.turn_right().delta();
+
+                let along = in_region(*label, pos, side);
+                let across = in_region(*label, pos, other_side);
+                let diagonal = in_region(*label, pos, side + other_side);
+
+                if !along {
+                    region.perimeter += 1;
+                }
+
+                // a side runs between two corners, outer ones where the region stops
+                // and inner ones where it turns in on itself, around holes too
+                let outer_corner = !along && !across;
+                let inner_corner = along && across && !diagonal;
+                if outer_corner || inner_corner {
+                    region.sides += 1;
+                }
+            }
+        }
+
+        geometry
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RegionGeometry {
+    // number of cells
+    pub area: usize,
+    // cell edges facing another region or the edge of the grid
+    pub perimeter: usize,
+    // straight runs of those edges, one per corner
+    // a band right round a torus has no corners, so its edges count as no sides
+    pub sides: usize,
+    // inclusive corners of the bounding box
+    pub min: (usize, usize),
+    pub max: (usize, usize),
+}
+
+// cells joined to their up, down, left and right neighbours when `same` says so
+// neighbours follow the grid's topology, so components wrap around a torus
+pub fn label_components<T>(grid: &Grid<T>, same: impl Fn(&T, &T) -> bool) -> Components {
+    let num_cols = grid.num_cols();
+    let index = |(row, col): (usize, usize)| row * num_cols + col;
+
+    let mut sets = UnionFind::new(grid.num_rows() * num_cols);
+
+    for (pos, cell) in grid.iter() {
+        for dir in [Direction::Right, Direction::Down] {
+            if let Some(next) = grid.step(pos, dir) {
+                if same(cell, &grid[next]) {
+                    sets.union(index(pos), index(next));
+                }
+            }
+        }
+    }
+
+    // roots to dense labels, in the order they're first seen
+    let mut root_labels = vec![None; sets.len()];
+    let mut stats: Vec<ComponentStats> = Vec::new();
+
+    let mut labels = Grid::from_elem(grid.dim(), 0).with_topology(grid.topology());
+
+    for pos in grid.positions() {
+        let root = sets.find(index(pos));
+        let label = *root_labels[root].get_or_insert_with(|| {
+            stats.push(ComponentStats {
+                size: 0,
+                first: pos,
+                min: pos,
+                max: pos,
+            });
+            stats.len() - 1
+        });
+
+        let component = &mut stats[label];
+        component.size += 1;
+        component.min = (component.min.0.min(pos.0), component.min.1.min(pos.1));
+        component.max = (component.max.0.max(pos.0), component.max.1.max(pos.1));
+
+        labels[pos] = label;
+    }
+
+    Components { labels, stats }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Topology;
+    use std::str::FromStr as _;
+
+    #[test]
+    fn union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.same_set(0, 3));
+        assert!(!sets.same_set(0, 4));
+        assert_eq!(sets.set_size(2), 4);
+        assert_eq!(sets.num_sets(), 3);
+    }
+
+    #[test]
+    fn grid_components() {
+        let grid: Grid<char> = Grid::from_str("AAB\nABB\nCCB").unwrap();
+        let components = label_components(&grid, |a, b| a == b);
+
+        assert_eq!(components.len(), 3);
+        assert_eq!(
+            components.labels.as_array(),
+            ndarray::arr2(&[[0, 0, 1], [0, 1, 1], [2, 2, 1]])
+        );
+        assert_eq!(
+            components.stats[1],
+            ComponentStats {
+                size: 4,
+                first: (0, 2),
+                min: (0, 1),
+                max: (2, 2),
+            }
+        );
+    }
+
+    #[test]
+    fn region_geometry() {
+        // an A with a hole, and two B regions that only touch at a corner
+        let grid: Grid<char> =
+            Grid::from_str("AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA").unwrap();
+        let components = label_components(&grid, |a, b| a == b);
+        let geometry = components.geometry();
+
+        let shapes: Vec<_> = geometry
+            .iter()
+            .map(|region| (region.area, region.perimeter, region.sides))
+            .collect();
+        assert_eq!(shapes, [(28, 40, 12), (4, 8, 4), (4, 8, 4)]);
+        assert_eq!((geometry[2].min, geometry[2].max), ((3, 1), (4, 2)));
+    }
+
+    #[test]
+    fn torus_geometry() {
+        // the corner As join across the seams into one square, and the Cs into a band
+        // the Bs are a band with a tongue, its top edge goes right round so only the
+        // tongue and the edge beside it count as sides
+        let grid: Grid<char> = Grid::from_str("ABBA\nCCCC\nBBBB\nABBA")
+            .unwrap()
+            .with_topology(Topology::Torus);
+        let components = label_components(&grid, |a, b| a == b);
+        let geometry = components.geometry();
+
+        let shapes: Vec<_> = geometry
+            .iter()
+            .map(|region| (region.area, region.perimeter, region.sides))
+            .collect();
+        assert_eq!(shapes, [(4, 8, 4), (8, 12, 4), (4, 8, 0)]);
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// where a sequence of states starts repeating itself
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    // steps taken before the first state that comes round again
+    pub start: usize,
+    // steps round the cycle
+    pub period: usize,
+}
+
+impl Cycle {
+    // the fewest steps that end on the same state as `steps` steps do
+    pub fn reduce(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.period
+        }
+    }
+}
+
+// Brent's algorithm, holding two states at a time
+// `step` gives None where the sequence ends, and then there's no cycle
+pub fn find_cycle<S: Clone + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    // the hare runs ahead in ever doubling stretches, the tortoise waits at the start of each
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare)?;
+        period += 1;
+    }
+
+    // a period apart, the two meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare)?;
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, period })
+}
+
+// remembers every state, so each is only stepped once
+// better than find_cycle when steps cost more than hashing
+pub fn find_cycle_hashed<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+
+    for steps in 0.. {
+        if let Some(start) = seen.insert(state.clone(), steps) {
+            return Some(Cycle {
+                start,
+                period: steps - start,
+            });
+        }
+        state = step(&state)?;
+    }
+
+    unreachable!()
+}
+
+// the state after `steps` steps, skipping round the cycle once it's found
+// None if the sequence ends before then
+pub fn state_after<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+    steps: usize,
+) -> Option<S> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut history = vec![initial];
+
+    while history.len() <= steps {
+        let state = &history[history.len() - 1];
+        if let Some(start) = seen.insert(state.clone(), history.len() - 1) {
+            let cycle = Cycle {
+                start,
+                period: history.len() - 1 - start,
+            };
+            return Some(history.swap_remove(cycle.reduce(steps)));
+        }
+
+        let next = step(state)?;
+        history.push(next);
+    }
+
+    history.pop()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles() {
+        // 3, 9, 81, 561, 721, ... and 81 again after 20 more
+        let square = |x: &u64| Some(x * x % 1000);
+        let expected = Cycle {
+            start: 2,
+            period: 20,
+        };
+        assert_eq!(find_cycle(3, square), Some(expected));
+        assert_eq!(find_cycle_hashed(3, square), Some(expected));
+        assert_eq!(expected.reduce(1), 1);
+        assert_eq!(expected.reduce(1_000_000_005), 5);
+
+        assert_eq!(state_after(3, square, 3), Some(561));
+        assert_eq!(
+            state_after(3, square, 1_000_000_005),
+            state_after(3, square, 5)
+        );
+
+        // runs out before it repeats
+        let count_down = |x: &u64| x.checked_sub(1);
+        assert_eq!(find_cycle(10, count_down), None);
+        assert_eq!(find_cycle_hashed(10, count_down), None);
+        assert_eq!(state_after(10, count_down, 4), Some(6));
+        assert_eq!(state_after(10, count_down, 11), None);
+    }
+}
@@ -0,0 +1,333 @@
+use crate::grid::Cell;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// a grid direction, rows grow downward
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // clockwise from up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    // where it comes in ALL
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn delta(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(-1, 0),
+            Direction::Right => Vector::new(0, 1),
+            Direction::Down => Vector::new(1, 0),
+            Direction::Left => Vector::new(0, -1),
+        }
+    }
+
+    // the direction of a single orthogonal step
+    pub fn from_delta(delta: Vector) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.delta() == delta)
+    }
+
+    // one of ^>v<
+    pub fn from_arrow(ch: char) -> Option<Self> {
+        match ch {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_arrow())
+    }
+}
+
+impl Cell for Direction {
+    fn from_char(ch: char) -> Option<Self> {
+        Self::from_arrow(ch)
+    }
+
+    fn to_char(&self) -> char {
+        self.to_arrow()
+    }
+}
+
+// a signed offset between grid positions
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Vector {
+    pub dr: i64,
+    pub dc: i64,
+}
+
+impl Vector {
+    pub const fn new(dr: i64, dc: i64) -> Self {
+        Self { dr, dc }
+    }
+
+    pub fn manhattan(self) -> u64 {
+        self.dr.unsigned_abs() + self.dc.unsigned_abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dr + other.dr, self.dc + other.dc)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dr, -self.dc)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector::new(self.dr * factor, self.dc * factor)
+    }
+}
+
+// a position on a grid, convertible to and from the (row, col) tuples grids index by
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    // None if the result is off a grid of size `dim`
+    pub fn offset(self, delta: Vector, dim: (usize, usize)) -> Option<Point> {
+        let row = self
+            .row
+            .checked_add_signed(isize::try_from(delta.dr).ok()?)?;
+        let col = self
+            .col
+            .checked_add_signed(isize::try_from(delta.dc).ok()?)?;
+
+        if row < dim.0 && col < dim.1 {
+            Some(Point::new(row, col))
+        } else {
+            None
+        }
+    }
+
+    // None if the step leaves a grid of size `dim`
+    pub fn step(self, dir: Direction, dim: (usize, usize)) -> Option<Point> {
+        self.offset(dir.delta(), dim)
+    }
+
+    // moved by delta on a grid of size `dim` whose edges join up
+    pub fn wrapping_offset(self, delta: Vector, dim: (usize, usize)) -> Point {
+        let wrap = |pos: usize, delta: i64, len: usize| {
+            let len = len as i64;
+            (pos as i64 + delta.rem_euclid(len)).rem_euclid(len) as usize
+        };
+
+        Point::new(
+            wrap(self.row, delta.dr, dim.0),
+            wrap(self.col, delta.dc, dim.1),
+        )
+    }
+
+    // moved by delta `times` times over, on a grid of size `dim` whose edges join up
+    pub fn wrapping_offset_times(self, delta: Vector, times: usize, dim: (usize, usize)) -> Point {
+        let wrap = |pos: usize, delta: i64, len: usize| {
+            let step = delta.rem_euclid(len as i64) as u128;
+            let moved = step * (times % len) as u128 % len as u128;
+            (pos + moved as usize) % len
+        };
+
+        Point::new(
+            wrap(self.row, delta.dr, dim.0),
+            wrap(self.col, delta.dc, dim.1),
+        )
+    }
+
+    pub fn vector_to(self, other: Point) -> Vector {
+        let dr = other.row as i64 - self.row as i64;
+        let dc = other.col as i64 - self.col as i64;
+        Vector::new(dr, dc)
+    }
+}
+
+// what happens at the edges of a grid
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Topology {
+    // moving off an edge leaves the grid
+    #[default]
+    Bounded,
+    // moving off an edge comes back on the opposite one
+    Torus,
+}
+
+impl Topology {
+    // None only if pos leaves a bounded grid
+    pub fn offset(self, pos: Point, delta: Vector, dim: (usize, usize)) -> Option<Point> {
+        match self {
+            Topology::Bounded => pos.offset(delta, dim),
+            Topology::Torus => Some(pos.wrapping_offset(delta, dim)),
+        }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row, col)
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            assert_eq!(dir.opposite().delta(), -dir.delta());
+            assert_eq!(Direction::from_delta(dir.delta()), Some(dir));
+            assert_eq!(Direction::from_arrow(dir.to_arrow()), Some(dir));
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::from_delta(Vector::new(1, 1)), None);
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert_eq!(format!("{}{}", Direction::Down, Direction::Left), "v<");
+    }
+
+    #[test]
+    fn checked_movement() {
+        let dim = (3, 4);
+        let corner = Point::new(0, 3);
+
+        assert_eq!(corner.step(Direction::Up, dim), None);
+        assert_eq!(corner.step(Direction::Right, dim), None);
+        assert_eq!(corner.step(Direction::Down, dim), Some(Point::new(1, 3)));
+        assert_eq!(
+            corner.offset(Vector::new(2, -3), dim),
+            Some(Point::new(2, 0))
+        );
+        assert_eq!(corner.offset(Vector::new(3, 0), dim), None);
+        assert_eq!(corner.offset(Vector::new(0, i64::MIN), dim), None);
+
+        let far = Point::new(2, 0);
+        assert_eq!(corner.vector_to(far), Vector::new(2, -3));
+        assert_eq!(corner.vector_to(far).manhattan(), 5);
+        assert_eq!(<(usize, usize)>::from(far), (2, 0));
+        assert_eq!(far.to_string(), "(2, 0)");
+    }
+
+    #[test]
+    fn wrapping() {
+        let dim = (3, 4);
+        let corner = Point::new(0, 3);
+
+        assert_eq!(
+            corner.wrapping_offset(Vector::new(-1, 1), dim),
+            Point::new(2, 0)
+        );
+        assert_eq!(
+            corner.wrapping_offset(Vector::new(7, -9), dim),
+            Point::new(1, 2)
+        );
+        assert_eq!(
+            corner.wrapping_offset(Vector::new(i64::MIN, i64::MAX), dim),
+            Point::new(1, 2)
+        );
+
+        assert_eq!(
+            corner.wrapping_offset_times(Vector::new(-1, 1), 5, dim),
+            Point::new(1, 0)
+        );
+        assert_eq!(
+            corner.wrapping_offset_times(Vector::new(7, -9), 0, dim),
+            corner
+        );
+        assert_eq!(
+            corner.wrapping_offset_times(Vector::new(i64::MIN, i64::MAX), usize::MAX, dim),
+            Point::new(0, 0)
+        );
+
+        assert_eq!(
+            Topology::Bounded.offset(corner, Vector::new(0, 1), dim),
+            None
+        );
+        assert_eq!(
+            Topology::Torus.offset(corner, Vector::new(0, 1), dim),
+            Some(Point::new(0, 0))
+        );
+    }
+}
@@ -1,6 +1,12 @@
+use crate::geom::{Direction, Topology, Vector};
+use crate::parse::ParseError;
+use crate::render::Render;
+use crate::stencil::Stencil;
 use anyhow::{anyhow, bail, Error};
 use ndarray::Array2;
-use std::fmt::{Display, Write as _};
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
 
 #[derive(Default)]
 struct GridShape {
@@ -29,50 +35,341 @@ impl GridShape {
     }
 }
 
-pub fn load_grid(rd: impl std::io::BufRead) -> Result<(Array2<char>, Option<String>), Error> {
-    let mut data = Vec::new();
-    let mut grid_shape = GridShape::default();
-    let mut extra: Option<String> = None;
+// a grid cell that can be read from and written as a single character
+pub trait Cell: Sized {
+    fn from_char(ch: char) -> Option<Self>;
 
-    for ln in rd.lines() {
-        let ln = ln?;
-        if let Some(extra) = &mut extra {
-            extra.push_str(&ln);
-            continue;
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    fn from_char(ch: char) -> Option<Self> {
+        Some(ch)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+// a rectangular grid indexed by (row, col)
+// bounded unless given another topology, which shift, step and around follow
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+    topology: Topology,
+}
+
+impl<T> Grid<T> {
+    pub fn from_elem(dim: (usize, usize), val: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_array(Array2::from_elem(dim, val))
+    }
+
+    pub fn from_array(cells: Array2<T>) -> Self {
+        Self {
+            cells,
+            topology: Topology::Bounded,
         }
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    pub fn as_array(&self) -> &Array2<T> {
+        &self.cells
+    }
+
+    pub fn into_array(self) -> Array2<T> {
+        self.cells
+    }
+
+    // (rows, cols)
+    pub fn dim(&self) -> (usize, usize) {
+        self.cells.dim()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.dim().0
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.dim().1
+    }
 
-        if ln.is_empty() {
-            extra = Some(String::new());
-            continue;
+    pub fn in_bounds(&self, pos: (usize, usize)) -> bool {
+        pos.0 < self.num_rows() && pos.1 < self.num_cols()
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    // several cells at once, None if any is off the grid or named twice
+    pub fn get_disjoint_mut<const N: usize>(
+        &mut self,
+        positions: [(usize, usize); N],
+    ) -> Option<[&mut T; N]> {
+        if !positions.iter().all(|pos| self.in_bounds(*pos)) {
+            return None;
         }
 
-        let mut col_count = 0;
-        for ch in ln.chars() {
-            data.push(ch);
-            col_count += 1;
+        let num_cols = self.num_cols();
+        let indices = positions.map(|(row, col)| row * num_cols + col);
+        self.cells.as_slice_mut()?.get_disjoint_mut(indices).ok()
+    }
+
+    // pos moved by delta, if that's still on the grid
+    pub fn offset(&self, pos: (usize, usize), delta: Vector) -> Option<(usize, usize)> {
+        let pos = self.topology.offset(pos.into(), delta, self.dim())?;
+        Some(pos.into())
+    }
+
+    // pos moved by (dr, dc), if that's still on the grid
+    pub fn shift(&self, pos: (usize, usize), dr: i64, dc: i64) -> Option<(usize, usize)> {
+        self.offset(pos, Vector::new(dr, dc))
+    }
+
+    // pos moved one step in dir, if that's still on the grid
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        self.offset(pos, dir.delta())
+    }
+
+    // the stencil's positions around pos that are on the grid
+    pub fn around<'a>(
+        &self,
+        pos: (usize, usize),
+        stencil: &'a Stencil,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        stencil.around_in(pos, self.dim(), self.topology)
+    }
+
+    // every position in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (num_rows, num_cols) = self.dim();
+        (0..num_rows).flat_map(move |row| (0..num_cols).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.indexed_iter()
+    }
+
+    // the position of the one cell matching `pred`, for markers like a start or a robot
+    pub fn find_unique(&self, pred: impl Fn(&T) -> bool) -> Result<(usize, usize), Error> {
+        let mut found = self.iter().filter(|(_pos, cell)| pred(cell));
+
+        let (pos, _cell) = found.next().ok_or_else(|| anyhow!("marker not found"))?;
+        if let Some((other, _cell)) = found.next() {
+            bail!("marker found more than once, at {pos:?} and {other:?}");
         }
 
-        grid_shape.add_row(col_count)?;
+        Ok(pos)
     }
 
-    let shape = grid_shape.calc()?;
-    let grid = Array2::from_shape_vec(shape, data).map_err(|_| anyhow!("bad array shape"))?;
-    Ok((grid, extra))
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.map(f),
+            topology: self.topology,
+        }
+    }
 }
 
-pub fn print_grid(grid: &Array2<impl Display>) {
-    let mut row_buf = String::new();
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
 
-    for (pos, val) in grid.indexed_iter() {
-        if pos.1 < row_buf.len() {
-            println!("{row_buf}");
-            row_buf.clear();
+    fn index(&self, pos: (usize, usize)) -> &T {
+        &self.cells[pos]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        &mut self.cells[pos]
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    pub fn from_lines<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Self, Error> {
+        Self::from_numbered_lines(1, lines)
+    }
+
+    // from_lines for a grid that starts partway into the input, errors give the input's line
+    pub(crate) fn from_numbered_lines<'a>(
+        first_line: usize,
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Result<Self, Error> {
+        let mut data = Vec::new();
+        let mut grid_shape = GridShape::default();
+
+        for (line_num, ln) in (first_line..).zip(lines) {
+            let mut col_count = 0;
+            for (col, ch) in ln.chars().enumerate() {
+                let cell = T::from_char(ch).ok_or_else(|| {
+                    ParseError::new(format!("bad grid char {ch:?}"))
+                        .with_line(line_num, ln)
+                        .with_col(col + 1)
+                })?;
+                data.push(cell);
+                col_count += 1;
+            }
+
+            grid_shape
+                .add_row(col_count)
+                .map_err(|err| ParseError::at_line(err, line_num, ln))?;
+        }
+
+        let shape = grid_shape
+            .calc()
+            .map_err(|err| ParseError::new(format!("{err:#}")))?;
+        let cells = Array2::from_shape_vec(shape, data).map_err(|_| anyhow!("bad array shape"))?;
+        Ok(Self::from_array(cells))
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_lines(s.lines())
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Render::grid(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum Tile {
+        Wall,
+        Open,
+    }
+
+    impl Cell for Tile {
+        fn from_char(ch: char) -> Option<Self> {
+            match ch {
+                '#' => Some(Tile::Wall),
+                '.' => Some(Tile::Open),
+                _ => None,
+            }
         }
 
-        write!(&mut row_buf, "{val}").unwrap();
+        fn to_char(&self) -> char {
+            match self {
+                Tile::Wall => '#',
+                Tile::Open => '.',
+            }
+        }
+    }
+
+    #[test]
+    fn parse_cells() {
+        let grid: Grid<Tile> = Grid::from_str("#..\n.#.").unwrap();
+        assert_eq!(grid.dim(), (2, 3));
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid[(1, 2)], Tile::Open);
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+
+        let err = Grid::<Tile>::from_str("#.\n.x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, col 2: bad grid char 'x'\n    .x\n     ^"
+        );
+
+        let err = Grid::<char>::from_str("ab\ncd\ne").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: inconsistent column count\n    e");
+
+        let err = Grid::<char>::from_str("").unwrap_err();
+        assert_eq!(err.to_string(), "no rows seen");
     }
 
-    if !row_buf.is_empty() {
-        println!("{row_buf}");
+    #[test]
+    fn numbered_line_errors() {
+        let grid: Grid<Direction> = Grid::from_str("^>\nv<").unwrap();
+        assert_eq!(grid[(1, 0)], Direction::Down);
+        assert_eq!(grid.to_string(), "^>\nv<\n");
+
+        // sections give the line they start on
+        let err = Grid::<Direction>::from_numbered_lines(5, ["<<", "<."].into_iter()).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line, err.col), (Some(6), Some(2)));
+    }
+
+    #[test]
+    fn bounds() {
+        let grid: Grid<Tile> = Grid::from_str("#..\n.#.").unwrap();
+        assert!(grid.in_bounds((1, 2)));
+        assert!(!grid.in_bounds((2, 0)));
+        assert!(!grid.in_bounds((0, 3)));
+        assert_eq!(grid.get((0, 3)), None);
+
+        assert_eq!(grid.shift((1, 2), -1, -2), Some((0, 0)));
+        assert_eq!(grid.shift((0, 0), -1, 0), None);
+        assert_eq!(grid.shift((1, 2), 0, 1), None);
+
+        let positions: Vec<_> = grid.positions().collect();
+        assert_eq!(positions.len(), 6);
+        assert_eq!(positions[3], (1, 0));
+    }
+
+    #[test]
+    fn unique_markers() {
+        let grid: Grid<char> = Grid::from_str("S..\n.#E\n..#").unwrap();
+        assert_eq!(grid.find_unique(|ch| *ch == 'E').unwrap(), (1, 2));
+
+        let err = grid.find_unique(|ch| *ch == 'X').unwrap_err();
+        assert_eq!(err.to_string(), "marker not found");
+
+        let err = grid.find_unique(|ch| *ch == '#').unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "marker found more than once, at (1, 1) and (2, 2)"
+        );
+    }
+
+    #[test]
+    fn disjoint_cells() {
+        let mut grid: Grid<char> = Grid::from_str("ab\ncd").unwrap();
+
+        let [a, d] = grid.get_disjoint_mut([(0, 0), (1, 1)]).unwrap();
+        std::mem::swap(a, d);
+        assert_eq!(grid.to_string(), "db\nca\n");
+
+        let [b, c, a] = grid.get_disjoint_mut([(0, 1), (1, 0), (1, 1)]).unwrap();
+        (*b, *c, *a) = (*a, *b, *c);
+        assert_eq!(grid.to_string(), "da\nbc\n");
+    }
+
+    #[test]
+    fn disjoint_cells_rejected() {
+        let mut grid: Grid<char> = Grid::from_str("ab\ncd").unwrap();
+
+        // the same cell twice, next to each other or not
+        assert!(grid.get_disjoint_mut([(0, 1), (0, 1)]).is_none());
+        assert!(grid.get_disjoint_mut([(0, 1), (1, 0), (0, 1)]).is_none());
+
+        // off the grid, including a column past the end that would land on the next row
+        assert!(grid.get_disjoint_mut([(0, 1), (2, 0)]).is_none());
+        assert!(grid.get_disjoint_mut([(0, 0), (0, 2)]).is_none());
+        assert!(grid.get_disjoint_mut([(usize::MAX, 0)]).is_none());
+
+        assert_eq!(grid.to_string(), "ab\ncd\n");
     }
 }
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
 fn parent(idx: usize) -> Option<usize> {
     if idx > 0 {
         Some((idx - 1) / 2)
@@ -14,90 +17,206 @@ fn right_child(idx: usize) -> usize {
     2 * idx + 2
 }
 
-// move an element in the tree up as needed
-// this is a max heap (biggest element at root)
-pub fn sift_up(heap: &mut [impl Ord], mut cur_idx: usize) -> usize {
-    loop {
-        let Some(parent_idx) = parent(cur_idx) else {
-            return cur_idx;
-        };
+// a min heap (smallest priority at root) that also tracks where each key is,
+// so a key's priority can be found and lowered without a scan
+pub struct IndexedHeap<K, P> {
+    entries: Vec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
 
-        if heap[cur_idx] >= heap[parent_idx] {
-            break cur_idx;
+impl<K, P> Default for IndexedHeap<K, P> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            positions: HashMap::new(),
         }
-
-        heap.swap(cur_idx, parent_idx);
-        cur_idx = parent_idx;
     }
 }
 
-// move an element in the tree down as needed
-// this is a max heap (biggest element at root)
-pub fn sift_down(heap: &mut [impl Ord], cur_idx: usize) {
-    let left_child_idx = left_child(cur_idx);
-    let right_child_idx = right_child(cur_idx);
+impl<K: Hash + Eq + Clone, P: Ord> IndexedHeap<K, P> {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    let mut largest_idx = cur_idx;
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
 
-    if left_child_idx < heap.len() && heap[left_child_idx] < heap[largest_idx] {
-        largest_idx = left_child_idx;
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
     }
 
-    if right_child_idx < heap.len() && heap[right_child_idx] < heap[largest_idx] {
-        largest_idx = right_child_idx;
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
     }
 
-    if largest_idx != cur_idx {
-        heap.swap(cur_idx, largest_idx);
-        sift_down(heap, largest_idx);
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        let idx = *self.positions.get(key)?;
+        Some(&self.entries[idx].1)
     }
-}
 
-fn heap_validate(heap: &[impl Ord], cur_idx: usize) -> bool {
-    // check left subtree
-    let left_idx = left_child(cur_idx);
-    let right_idx = left_child(cur_idx);
+    // the key must not already be in the heap
+    pub fn push(&mut self, key: K, priority: P) {
+        let idx = self.entries.len();
+        let prev = self.positions.insert(key.clone(), idx);
+        assert!(prev.is_none(), "key pushed twice");
 
-    if left_idx < heap.len() {
-        if heap[cur_idx] > heap[left_idx] || !heap_validate(heap, left_idx) {
-            return false;
+        self.entries.push((key, priority));
+        let idx = self.sift_up(idx);
+        debug_assert!(self.is_valid_at(idx));
+    }
+
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.entries.is_empty() {
+            return None;
         }
+
+        let last_idx = self.entries.len() - 1;
+        self.swap(0, last_idx);
+        let (key, priority) = self.entries.pop()?;
+        self.positions.remove(&key);
+
+        let idx = self.sift_down(0);
+        debug_assert!(self.is_valid_at(idx));
+        Some((key, priority))
     }
 
-    // check right subtree
-    if right_idx < heap.len() {
-        if heap[cur_idx] > heap[right_idx] || !heap_validate(heap, right_idx) {
+    // lowers the key's priority, returns false if the key isn't in the heap
+    // or already has a priority at least as low
+    pub fn decrease_key(&mut self, key: &K, priority: P) -> bool {
+        let Some(&idx) = self.positions.get(key) else {
+            return false;
+        };
+
+        if priority >= self.entries[idx].1 {
             return false;
         }
+
+        self.entries[idx].1 = priority;
+        let idx = self.sift_up(idx);
+        debug_assert!(self.is_valid_at(idx));
+        true
     }
 
-    true
-}
+    // push a new key, or lower the priority of one already in the heap
+    pub fn push_or_decrease(&mut self, key: K, priority: P) {
+        if self.contains(&key) {
+            self.decrease_key(&key, priority);
+        } else {
+            self.push(key, priority);
+        }
+    }
 
-pub fn heap_push<T: Ord>(heap: &mut Vec<T>, elem: T) -> usize {
-    let mut cur_idx = heap.len();
-    heap.push(elem);
+    fn swap(&mut self, a: usize, b: usize) {
+        self.entries.swap(a, b);
+        *self.positions.get_mut(&self.entries[a].0).unwrap() = a;
+        *self.positions.get_mut(&self.entries[b].0).unwrap() = b;
+    }
 
-    cur_idx = sift_up(heap, cur_idx);
-    assert!(heap_validate(heap, 0));
-    cur_idx
-}
+    // move an element in the tree up as needed, returns where it ended up
+    fn sift_up(&mut self, mut cur_idx: usize) -> usize {
+        while let Some(parent_idx) = parent(cur_idx) {
+            if self.entries[cur_idx].1 >= self.entries[parent_idx].1 {
+                break;
+            }
+
+            self.swap(cur_idx, parent_idx);
+            cur_idx = parent_idx;
+        }
+
+        cur_idx
+    }
 
-pub fn heap_pop<T: Ord>(heap: &mut Vec<T>) -> Option<T> {
-    if heap.is_empty() {
-        return None;
+    // move an element in the tree down as needed, returns where it ended up
+    fn sift_down(&mut self, mut cur_idx: usize) -> usize {
+        loop {
+            let mut smallest_idx = cur_idx;
+
+            for child_idx in [left_child(cur_idx), right_child(cur_idx)] {
+                if child_idx < self.entries.len()
+                    && self.entries[child_idx].1 < self.entries[smallest_idx].1
+                {
+                    smallest_idx = child_idx;
+                }
+            }
+
+            if smallest_idx == cur_idx {
+                break;
+            }
+
+            self.swap(cur_idx, smallest_idx);
+            cur_idx = smallest_idx;
+        }
+
+        cur_idx
     }
 
-    let elem = heap.swap_remove(0);
-    sift_down(heap, 0);
-    assert!(heap_validate(heap, 0));
-    assert!(heap.iter().all(|e| *e >= elem));
-    Some(elem)
+    // the entry a sift stopped at sits between its parent and its children, and
+    // its key knows where it is; cheap enough to check after every change
+    fn is_valid_at(&self, idx: usize) -> bool {
+        let Some((key, priority)) = self.entries.get(idx) else {
+            // popped the last entry
+            return self.entries.is_empty();
+        };
+
+        let below_parent =
+            parent(idx).is_none_or(|parent_idx| self.entries[parent_idx].1 <= *priority);
+        let above_children = [left_child(idx), right_child(idx)]
+            .into_iter()
+            .filter_map(|child_idx| self.entries.get(child_idx))
+            .all(|(_child_key, child_priority)| priority <= child_priority);
+
+        below_parent && above_children && self.positions.get(key) == Some(&idx)
+    }
+
+    // every parent is no bigger than its children and every key's position is right
+    #[cfg(test)]
+    fn is_valid(&self) -> bool {
+        let ordered = (1..self.entries.len())
+            .all(|idx| self.entries[parent(idx).unwrap()].1 <= self.entries[idx].1);
+
+        let indexed = self.positions.len() == self.entries.len()
+            && self
+                .entries
+                .iter()
+                .enumerate()
+                .all(|(idx, (key, _priority))| self.positions.get(key) == Some(&idx));
+
+        ordered && indexed
+    }
 }
 
-pub fn heap_decrease<T: Ord>(heap: &mut [T], cur_idx: usize) -> usize {
-    let cur_idx = sift_up(heap, cur_idx);
-    assert!(heap_validate(heap, 0));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pops_in_priority_order() {
+        let mut heap = IndexedHeap::new();
+
+        for (key, priority) in [('a', 5), ('b', 3), ('c', 8), ('d', 1), ('e', 7)] {
+            heap.push(key, priority);
+        }
+
+        assert!(heap.is_valid());
 
-    cur_idx
+        assert!(heap.decrease_key(&'c', 2));
+        assert!(!heap.decrease_key(&'a', 6));
+        assert!(!heap.decrease_key(&'z', 0));
+        heap.push_or_decrease('e', 4);
+        heap.push_or_decrease('f', 6);
+        assert!(heap.is_valid());
+
+        assert!(heap.contains(&'e'));
+        assert_eq!(heap.priority(&'e'), Some(&4));
+
+        let mut popped = Vec::new();
+        while let Some((key, _priority)) = heap.pop() {
+            assert!(heap.is_valid());
+            popped.push(key);
+        }
+
+        assert_eq!(popped, ['d', 'c', 'b', 'e', 'a', 'f']);
+        assert!(!heap.contains(&'e'));
+    }
 }
@@ -0,0 +1,136 @@
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+// handed to a generator's body, which yields through it
+pub struct Co<T> {
+    slot: Rc<Cell<Option<T>>>,
+}
+
+impl<T> Co<T> {
+    // hands val to the iterator, the body picks up again when the next item is asked for
+    pub fn yield_(&self, val: T) -> impl Future<Output = ()> {
+        self.slot.set(Some(val));
+        YieldNow { yielded: false }
+    }
+}
+
+// pending once, so the generator stops at each yield
+struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            Poll::Pending
+        }
+    }
+}
+
+pub struct Generator<'a, T> {
+    slot: Rc<Cell<Option<T>>>,
+    body: Option<Pin<Box<dyn Future<Output = ()> + 'a>>>,
+}
+
+impl<T> Iterator for Generator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let body = self.body.as_mut()?;
+
+        let val = match body.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Pending => self.slot.take(),
+            Poll::Ready(()) => None,
+        };
+
+        // finished, or stuck on something other than a yield, either way it's done
+        if val.is_none() {
+            self.body = None;
+        }
+
+        val
+    }
+}
+
+// an iterator written as a loop that yields, run lazily a step at a time:
+//   generate(|co| async move { for x in 0..3 { co.yield_(x).await; } })
+// the body should only await its own yields, anything else ends the iterator
+pub fn generate<'a, T, Fut>(body: impl FnOnce(Co<T>) -> Fut) -> Generator<'a, T>
+where
+    Fut: Future<Output = ()> + 'a,
+{
+    let slot = Rc::new(Cell::new(None));
+    let body = body(Co { slot: slot.clone() });
+
+    Generator {
+        slot,
+        body: Some(Box::pin(body)),
+    }
+}
+
+// every run of N neighbouring items, as arrays
+pub fn array_windows<T, const N: usize>(items: &[T]) -> impl Iterator<Item = &[T; N]> {
+    items
+        .windows(N)
+        .map(|window| window.try_into().expect("windows are N long"))
+}
+
+// the items N at a time, and the ones left over at the end
+pub fn array_chunks<T, const N: usize>(items: &[T]) -> (impl Iterator<Item = &[T; N]>, &[T]) {
+    let chunks = items.chunks_exact(N);
+    let rest = chunks.remainder();
+    let chunks = chunks.map(|chunk| chunk.try_into().expect("chunks are N long"));
+    (chunks, rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generators() {
+        let squares: Vec<u32> = generate(|co| async move {
+            for x in 1..=4 {
+                co.yield_(x * x).await;
+            }
+        })
+        .collect();
+        assert_eq!(squares, [1, 4, 9, 16]);
+
+        // lazy, so an endless body is fine
+        let mut count = 0;
+        let evens = generate(|co| async move {
+            loop {
+                co.yield_(count).await;
+                count += 2;
+            }
+        });
+        assert_eq!(evens.take(3).collect::<Vec<_>>(), [0, 2, 4]);
+
+        // fused, even when the body waits on something other than a yield
+        let mut stuck = generate(|co| async move {
+            co.yield_(1).await;
+            YieldNow { yielded: false }.await;
+            co.yield_(2).await;
+        });
+        assert_eq!(stuck.next(), Some(1));
+        assert_eq!(stuck.next(), None);
+        assert_eq!(stuck.next(), None);
+
+        let nums = [1, 2, 3, 4, 5];
+        let sums: Vec<i32> = array_windows(&nums).map(|[a, b]| a + b).collect();
+        assert_eq!(sums, [3, 5, 7, 9]);
+
+        let (pairs, rest) = array_chunks::<_, 2>(&nums);
+        assert_eq!(pairs.collect::<Vec<_>>(), [&[1, 2], &[3, 4]]);
+        assert_eq!(rest, [5]);
+    }
+}
@@ -0,0 +1,415 @@
+use crate::settings::Settings;
+use anyhow::{anyhow, bail, Error};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Write as _;
+use std::path::Path;
+use std::str::FromStr;
+
+// a record of every answer submitted for a day, one per line:
+//   <input> <part> <verdict> <answer>
+// e.g. `input02.txt 1 too-high 556`
+// `pinned` answers are what a solution gave that were never submitted,
+// kept to catch regressions but not trusted like `correct` ones
+// an input's puzzle settings, when they differ from the full input's, go in
+// lines of their own:
+//   <input> set <name> <value>
+// blank lines and lines starting with # are ignored
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Pinned,
+}
+
+impl FromStr for Verdict {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "pinned" => Ok(Verdict::Pinned),
+            _ => bail!("invalid verdict {s:?}"),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Pinned => "pinned",
+        };
+
+        f.write_str(s)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub input: String,
+    pub part: u32,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.splitn(4, ' ');
+        let mut next = || {
+            tokens
+                .next()
+                .ok_or_else(|| anyhow!("ledger entry too short"))
+        };
+
+        let input = next()?.to_owned();
+        let part = u32::from_str(next()?)?;
+        let verdict = Verdict::from_str(next()?)?;
+        let answer = next()?.trim().to_owned();
+
+        if !matches!(part, 1 | 2) {
+            bail!("invalid part {part}");
+        }
+
+        if answer.is_empty() {
+            bail!("ledger entry missing answer");
+        }
+
+        Ok(Entry {
+            input,
+            part,
+            verdict,
+            answer,
+        })
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.input, self.part, self.verdict, self.answer
+        )
+    }
+}
+
+// what the ledger says about a new answer
+pub enum Check<'a> {
+    // matches the accepted answer
+    Correct,
+    // differs from the accepted answer
+    Incorrect { expected: &'a str },
+    // already submitted and rejected
+    KnownWrong(&'a Entry),
+    // on the wrong side of an earlier too-high or too-low guess
+    OutOfBounds(&'a Entry),
+    // matches the pinned answer, which was never confirmed
+    Pinned,
+    // differs from the pinned answer
+    Changed { pinned: &'a str },
+    // nothing known, may be worth submitting
+    Unknown,
+}
+
+impl Display for Check<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Check::Correct => write!(f, "correct"),
+            Check::Incorrect { expected } => write!(f, "WRONG, expected {expected}"),
+            Check::KnownWrong(entry) => {
+                write!(f, "WARNING: already submitted, was {}", entry.verdict)
+            }
+            Check::OutOfBounds(entry) => {
+                write!(f, "WARNING: {} was already {}", entry.answer, entry.verdict)
+            }
+            Check::Pinned => write!(f, "pinned, unverified"),
+            Check::Changed { pinned } => write!(f, "CHANGED, was pinned at {pinned}"),
+            Check::Unknown => write!(f, "unverified"),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Ledger {
+    entries: Vec<Entry>,
+    // keyed by input
+    settings: HashMap<String, Settings>,
+}
+
+impl Ledger {
+    // a missing ledger file is an empty ledger
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::from_str(&text).map_err(|err| anyhow!("{}: {err:#}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    // the settings to solve the input with, none if it uses the full input's
+    pub fn settings(&self, input: &str) -> Settings {
+        self.settings.get(input).cloned().unwrap_or_default()
+    }
+
+    pub fn expected(&self, input: &str, part: u32) -> Option<&str> {
+        self.answer_with(input, part, Verdict::Correct)
+    }
+
+    pub fn pinned(&self, input: &str, part: u32) -> Option<&str> {
+        self.answer_with(input, part, Verdict::Pinned)
+    }
+
+    fn answer_with(&self, input: &str, part: u32, verdict: Verdict) -> Option<&str> {
+        self.entries_for(input, part)
+            .find(|entry| entry.verdict == verdict)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    pub fn check(&self, input: &str, part: u32, answer: &str) -> Check<'_> {
+        if let Some(expected) = self.expected(input, part) {
+            return if expected == answer {
+                Check::Correct
+            } else {
+                Check::Incorrect { expected }
+            };
+        }
+
+        if let Some(entry) = self
+            .entries_for(input, part)
+            .find(|entry| entry.answer == answer && entry.verdict != Verdict::Pinned)
+        {
+            return Check::KnownWrong(entry);
+        }
+
+        // numeric answers can also be ruled out by too-high/too-low hints,
+        // even one that matches the pin
+        if let Ok(number) = i128::from_str(answer) {
+            for entry in self.entries_for(input, part) {
+                let Ok(guess) = i128::from_str(&entry.answer) else {
+                    continue;
+                };
+
+                let out_of_bounds = match entry.verdict {
+                    Verdict::TooHigh => number >= guess,
+                    Verdict::TooLow => number <= guess,
+                    Verdict::Correct | Verdict::Wrong | Verdict::Pinned => false,
+                };
+
+                if out_of_bounds {
+                    return Check::OutOfBounds(entry);
+                }
+            }
+        }
+
+        if let Some(pinned) = self.pinned(input, part) {
+            return if pinned == answer {
+                Check::Pinned
+            } else {
+                Check::Changed { pinned }
+            };
+        }
+
+        Check::Unknown
+    }
+
+    // add an entry to the ledger file at `path`, creating it if needed
+    pub fn record(path: &Path, entry: &Entry) -> Result<(), Error> {
+        let ledger = Self::load(path)?;
+
+        if entry.verdict == Verdict::Correct {
+            if let Some(expected) = ledger.expected(&entry.input, entry.part) {
+                bail!("already have a correct answer: {expected}");
+            }
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{entry}")?;
+        Ok(())
+    }
+
+    fn entries_for<'a, 'b>(
+        &'a self,
+        input: &'b str,
+        part: u32,
+    ) -> impl Iterator<Item = &'a Entry> + use<'a, 'b> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.input == input && entry.part == part)
+    }
+}
+
+impl FromStr for Ledger {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = Vec::new();
+        let mut settings: HashMap<String, Settings> = HashMap::new();
+
+        for (line_num, ln) in s.lines().enumerate() {
+            let ln = ln.trim();
+            if ln.is_empty() || ln.starts_with('#') {
+                continue;
+            }
+
+            let tokens: Vec<&str> = ln.split(' ').collect();
+            match tokens.as_slice() {
+                [input, "set", name, value] => {
+                    settings
+                        .entry(input.to_string())
+                        .or_default()
+                        .set(name, value);
+                }
+                [_input, "set", ..] => {
+                    bail!("line {}: expected a setting name and value", line_num + 1)
+                }
+                _ => {
+                    let entry = Entry::from_str(ln)
+                        .map_err(|err| anyhow!("line {}: {err}", line_num + 1))?;
+                    entries.push(entry);
+                }
+            }
+        }
+
+        Ok(Ledger { entries, settings })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = "\
+# <input> <part> <verdict> <answer>
+
+input02.txt 1 too-high 556
+input02.txt 1 too-low 200
+input02.txt 1 wrong 300
+input02.txt 1 correct 268
+input02.txt 2 wrong 64,11
+input02.txt 2 too-low 10
+input01.txt 2 pinned 6
+input01.txt set size 7
+";
+
+    #[test]
+    fn ledger_file() {
+        let ledger = Ledger::from_str(LEDGER).unwrap();
+        assert_eq!(ledger.entries.len(), 7);
+        assert_eq!(ledger.entries[0].verdict, Verdict::TooHigh);
+        assert_eq!(ledger.entries[6].to_string(), "input01.txt 2 pinned 6");
+        assert_eq!(ledger.expected("input02.txt", 1), Some("268"));
+        assert_eq!(ledger.expected("input02.txt", 2), None);
+        assert_eq!(ledger.pinned("input01.txt", 2), Some("6"));
+        assert_eq!(
+            ledger.settings("input01.txt").get_or("size", 71).unwrap(),
+            7
+        );
+        assert_eq!(
+            ledger.settings("input02.txt").get_or("size", 71).unwrap(),
+            71
+        );
+
+        assert!(matches!(
+            ledger.check("input02.txt", 1, "268"),
+            Check::Correct
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 1, "300"),
+            Check::Incorrect { expected: "268" }
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "64,11"),
+            Check::KnownWrong(Entry {
+                verdict: Verdict::Wrong,
+                ..
+            })
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "10"),
+            Check::KnownWrong(_)
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "9"),
+            Check::OutOfBounds(Entry {
+                verdict: Verdict::TooLow,
+                ..
+            })
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "11"),
+            Check::Unknown
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "1,2"),
+            Check::Unknown
+        ));
+        assert!(matches!(ledger.check("input01.txt", 2, "6"), Check::Pinned));
+        assert!(matches!(
+            ledger.check("input01.txt", 2, "7"),
+            Check::Changed { pinned: "6" }
+        ));
+
+        for (bad, message) in [
+            ("input02.txt 1 maybe 5", "line 1: invalid verdict \"maybe\""),
+            ("input02.txt 3 wrong 5", "line 1: invalid part 3"),
+            ("\ninput02.txt 1 wrong", "line 2: ledger entry too short"),
+            (
+                "input01.txt set size",
+                "line 1: expected a setting name and value",
+            ),
+        ] {
+            let err = Ledger::from_str(bad).err().unwrap();
+            assert_eq!(err.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn pins_within_bounds() {
+        let ledger = Ledger::from_str(
+            "\
+input02.txt 1 too-high 500
+input02.txt 1 pinned 600
+input02.txt 2 too-low 10
+input02.txt 2 pinned 20
+",
+        )
+        .unwrap();
+
+        // a pin the site already turned down is no better than any other guess
+        assert!(matches!(
+            ledger.check("input02.txt", 1, "600"),
+            Check::OutOfBounds(Entry {
+                verdict: Verdict::TooHigh,
+                ..
+            })
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 1, "400"),
+            Check::Changed { pinned: "600" }
+        ));
+
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "20"),
+            Check::Pinned
+        ));
+        assert!(matches!(
+            ledger.check("input02.txt", 2, "5"),
+            Check::OutOfBounds(_)
+        ));
+    }
+}
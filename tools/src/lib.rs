@@ -1,33 +1,39 @@
-#![feature(coroutine_trait)]
+mod bench;
+mod bits;
+mod components;
+mod cycle;
+mod geom;
 mod grid;
 mod heap;
-mod misc;
+mod iter;
+mod ledger;
+mod memo;
+pub mod parse;
+mod render;
+mod replay;
+mod search;
+mod sections;
+mod settings;
+mod solution;
+mod sparse;
+mod stencil;
 
-pub use grid::load_grid;
-pub use grid::print_grid;
-pub use heap::{heap_decrease, heap_pop, heap_push};
-pub use misc::iter_coro;
-
-use ndarray::Array2;
-
-pub const DELTAS: &[(i64, i64)] = &[(0, 1), (0, -1), (-1, 0), (1, 0)];
-
-pub fn shift<T>(
-    heights: &Array2<T>,
-    pos: (usize, usize),
-    dr: i64,
-    dc: i64,
-) -> Option<(usize, usize)> {
-    let row = i64::try_from(pos.0).ok()?;
-    let col = i64::try_from(pos.1).ok()?;
-    let row = row.checked_add(dr)?;
-    let col = col.checked_add(dc)?;
-    let row = usize::try_from(row).ok()?;
-    let col = usize::try_from(col).ok()?;
-
-    if row < heights.dim().0 && col < heights.dim().1 {
-        Some((row, col))
-    } else {
-        None
-    }
-}
+pub use bench::{bench, Record, Samples, Stage, Timings};
+pub use bits::{BitGrid, DirSet};
+pub use components::{label_components, ComponentStats, Components, RegionGeometry, UnionFind};
+pub use cycle::{find_cycle, find_cycle_hashed, state_after, Cycle};
+pub use geom::{Direction, Point, Topology, Vector};
+pub use grid::{Cell, Grid};
+pub use heap::IndexedHeap;
+pub use iter::{array_chunks, array_windows, generate, Co, Generator};
+pub use ledger::{Check, Entry, Ledger, Verdict};
+pub use memo::{Memo, MemoStats, Recurse};
+pub use parse::ParseError;
+pub use render::{Color, Layer, Render};
+pub use replay::{NoRecording, Recorder, Recording, Replay};
+pub use search::{astar, bfs, bfs_all, dijkstra, dijkstra_all, DistanceMap, SearchResult};
+pub use sections::{Section, Sections};
+pub use settings::Settings;
+pub use solution::{main, parse_input, record, solve, Answers, Parsed, Part, Solution};
+pub use sparse::{BoundingBox, SparseGrid};
+pub use stencil::Stencil;
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+
+// how a memoised function makes its recursive calls, so they go through the cache too
+pub type Recurse<'a, K, V> = dyn FnMut(K) -> V + 'a;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl MemoStats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+// results of a recursive function, keyed by its state
+// the cache holds for one meaning of the function, clear it when that changes, like between inputs
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: MemoStats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stats: MemoStats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // f(key), with f's calls to `recurse` looked up or cached the same way
+    pub fn get(&mut self, key: K, f: impl Fn(&mut Recurse<K, V>, K) -> V) -> V {
+        self.lookup(key, &f)
+    }
+
+    fn lookup(&mut self, key: K, f: &impl Fn(&mut Recurse<K, V>, K) -> V) -> V {
+        if let Some(val) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return val.clone();
+        }
+
+        self.stats.misses += 1;
+        let val = f(&mut |sub_key| self.lookup(sub_key, f), key.clone());
+        self.cache.insert(key, val.clone());
+        val
+    }
+
+    // forgets the results, the stats keep counting
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        self.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(recurse: &mut Recurse<u64, u64>, n: u64) -> u64 {
+        match n {
+            0 | 1 => n,
+            _ => recurse(n - 1) + recurse(n - 2),
+        }
+    }
+
+    #[test]
+    fn recursive_calls() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.get(80, fib), 23416728348467685);
+        assert_eq!(memo.len(), 81);
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 78,
+                misses: 81
+            }
+        );
+
+        assert_eq!(memo.get(10, fib), 55);
+        assert_eq!(memo.stats().hits, 79);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.get(10, fib), 55);
+        assert_eq!(memo.stats().misses, 92);
+    }
+}
@@ -0,0 +1,342 @@
+use anyhow::Error;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, char, digit1, one_of, space0, space1};
+use nom::combinator::{all_consuming, map_res, opt, recognize};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, preceded, separated_pair, tuple};
+use nom::{IResult, Parser};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// where in the input parsing went wrong, each part filled in by whoever knows it:
+// the parser knows the column, the line loop the line, and the runner the file
+#[derive(Debug, Default)]
+pub struct ParseError {
+    pub source: Option<String>,
+    // 1-based
+    pub line: Option<usize>,
+    // 1-based, in chars
+    pub col: Option<usize>,
+    // the offending line
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_line(mut self, line: usize, text: &str) -> Self {
+        self.line = Some(line);
+        self.text = Some(text.to_owned());
+        self
+    }
+
+    pub fn with_col(mut self, col: usize) -> Self {
+        self.col = Some(col);
+        self
+    }
+
+    // err located at a line of the input
+    // a ParseError keeps whatever it already knows, anything else becomes one
+    pub fn at_line(err: Error, line: usize, text: &str) -> Error {
+        let mut err = match err.downcast::<ParseError>() {
+            Ok(err) => err,
+            Err(err) => ParseError::new(format!("{err:#}")),
+        };
+
+        if err.line.is_none() {
+            err.line = Some(line);
+            err.text = Some(text.to_owned());
+        }
+
+        err.into()
+    }
+
+    // names the input a ParseError came from, other errors are left alone
+    pub fn in_source(mut err: Error, source: &str) -> Error {
+        if let Some(parse_err) = err.downcast_mut::<ParseError>() {
+            parse_err.source.get_or_insert_with(|| source.to_owned());
+        }
+
+        err
+    }
+}
+
+// "input02.txt, line 3, col 5: message", then the line with a caret under the column
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let location: Vec<String> = [
+            self.source.clone(),
+            self.line.map(|line| format!("line {line}")),
+            self.col.map(|col| format!("col {col}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.message)?;
+
+        if let Some(text) = &self.text {
+            write!(f, "\n    {text}")?;
+
+            if let Some(col) = self.col {
+                write!(f, "\n    {:>col$}", "^")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// every line parsed by `f`, errors are located at their line
+pub fn parse_lines<'a, T>(
+    text: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    parse_numbered_lines(1, text.lines(), f)
+}
+
+// parse_lines for lines that start partway into the input
+pub(crate) fn parse_numbered_lines<'a, T>(
+    first_line: usize,
+    lines: impl Iterator<Item = &'a str>,
+    mut f: impl FnMut(&'a str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    (first_line..)
+        .zip(lines)
+        .map(|(line_num, ln)| f(ln).map_err(|err| ParseError::at_line(err, line_num, ln)))
+        .collect()
+}
+
+// nom parsers over &str, for the shapes that keep turning up in the inputs
+
+// the whole of `input` parsed by `parser`, errors give the column it stopped at
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> Result<T, Error> {
+    match all_consuming(parser)(input) {
+        Ok((_rest, val)) => Ok(val),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let parsed = &input[..input.len() - err.input.len()];
+            let col = parsed.chars().count() + 1;
+
+            let found = match err.input {
+                "" => "end of line".to_owned(),
+                rest => format!("{rest:?}"),
+            };
+
+            Err(ParseError {
+                col: Some(col),
+                text: Some(input.to_owned()),
+                ..ParseError::new(format!("unexpected {found}"))
+            }
+            .into())
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new("incomplete input").into()),
+    }
+}
+
+// every match of `parser` in `input`, skipping a char at a time past anything else
+pub fn find_all<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> Vec<T> {
+    let mut found = Vec::new();
+    let mut rest = input;
+
+    while let Some(ch) = rest.chars().next() {
+        match parser.parse(rest) {
+            Ok((after, val)) if after.len() < rest.len() => {
+                found.push(val);
+                rest = after;
+            }
+            _ => rest = &rest[ch.len_utf8()..],
+        }
+    }
+
+    found
+}
+
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, T::from_str)(input)
+}
+
+// with an optional leading '-' or '+'
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), |num: &str| {
+        T::from_str(num.trim_start_matches('+'))
+    })(input)
+}
+
+// letters and digits, like a wire or register name
+pub fn word(input: &str) -> IResult<&str, &str> {
+    alphanumeric1(input)
+}
+
+// one or more items split by `sep`
+pub fn list<'a, T>(
+    sep: &'a str,
+    item: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(tag(sep), item)
+}
+
+// signed integers split by `sep`, "3 -1 4" or "3,-1,4"
+pub fn int_list<'a, T: FromStr>(sep: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    list(sep, signed)
+}
+
+// items split by commas, with or without spaces after them
+pub fn comma_list<'a, T>(
+    item: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+// `<key><sep><value>`, like "x00: 1" or "190: 10 19"
+pub fn key_value<'a, K, V>(
+    key: impl Parser<&'a str, K, nom::error::Error<&'a str>>,
+    sep: &'a str,
+    value: impl Parser<&'a str, V, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (K, V)> {
+    separated_pair(key, tag(sep), value)
+}
+
+// two unsigned numbers split by `sep`, like the rule "47|53"
+pub fn rule<T: FromStr>(sep: char) -> impl FnMut(&str) -> IResult<&str, (T, T)> {
+    move |input| separated_pair(unsigned, char(sep), unsigned)(input)
+}
+
+// `<label>=x,y`, like "p=0,4" or "v=-3,3"
+pub fn labeled_xy<'a, T: FromStr>(
+    label: &'a str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (T, T)> {
+    preceded(
+        pair(tag(label), char('=')),
+        separated_pair(signed, char(','), signed),
+    )
+}
+
+// `X<op>x, Y<op>y`, like "X+94, Y+34" or "X=8400, Y=5400"
+pub fn axes<T: FromStr>(op: char) -> impl FnMut(&str) -> IResult<&str, (T, T)> {
+    move |input| {
+        separated_pair(
+            preceded(pair(char('X'), char(op)), unsigned),
+            tag(", "),
+            preceded(pair(char('Y'), char(op)), unsigned),
+        )(input)
+    }
+}
+
+// `<in> <op> <in> -> <out>`, like "x00 AND y00 -> z00"
+// gives (left input, operator, right input, output)
+pub fn gate<'a, O>(
+    op: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, O, &'a str, &'a str)> {
+    let mut line = tuple((
+        word,
+        preceded(space1, op),
+        preceded(space1, word),
+        preceded(tuple((space1, tag("->"), space1)), word),
+    ));
+
+    move |input| line.parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::branch::alt;
+    use nom::combinator::value;
+    use nom::sequence::delimited;
+
+    #[test]
+    fn shapes() {
+        assert_eq!(
+            parse_all("3 -1 +4", int_list::<i64>(" ")).unwrap(),
+            [3, -1, 4]
+        );
+        assert_eq!(parse_all("47|53", rule::<u32>('|')).unwrap(), (47, 53));
+        assert_eq!(
+            parse_all("r, wr, b", comma_list(word)).unwrap(),
+            ["r", "wr", "b"]
+        );
+        assert_eq!(
+            parse_all(
+                "190: 10 19",
+                key_value(unsigned::<u64>, ": ", int_list::<u64>(" "))
+            )
+            .unwrap(),
+            (190, vec![10, 19])
+        );
+        assert_eq!(
+            parse_all(
+                "p=0,4 v=3,-3",
+                separated_pair(labeled_xy::<i64>("p"), space1, labeled_xy::<i64>("v"))
+            )
+            .unwrap(),
+            ((0, 4), (3, -3))
+        );
+        assert_eq!(
+            parse_all(
+                "Button A: X+94, Y+34",
+                preceded(tag("Button A: "), axes::<u64>('+'))
+            )
+            .unwrap(),
+            (94, 34)
+        );
+
+        let op = alt((value(true, tag("AND")), value(false, tag("OR"))));
+        assert_eq!(
+            parse_all("x00 AND y00 -> z00", gate(op)).unwrap(),
+            ("x00", true, "y00", "z00")
+        );
+
+        let err = parse_all("1,2,x", comma_list(unsigned::<u32>)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "col 4: unexpected \",x\"\n    1,2,x\n       ^"
+        );
+    }
+
+    #[test]
+    fn located_errors() {
+        let text = "1,2\n3,x\n";
+        let err = parse_lines(text, |ln| parse_all(ln, comma_list(unsigned::<u32>))).unwrap_err();
+        let err = ParseError::in_source(err, "input01.txt");
+        assert_eq!(
+            err.to_string(),
+            "input01.txt, line 2, col 2: unexpected \",x\"\n    3,x\n     ^"
+        );
+
+        let err = parse_lines(text, |_ln| Err::<(), _>(anyhow::anyhow!("no good"))).unwrap_err();
+        let parse_err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((parse_err.line, parse_err.col), (Some(1), None));
+        assert_eq!(err.to_string(), "line 1: no good\n    1,2");
+
+        let err = ParseError::in_source(anyhow::anyhow!("not parsing"), "input01.txt");
+        assert_eq!(err.to_string(), "not parsing");
+    }
+
+    #[test]
+    fn find_in_junk() {
+        let muls = delimited(
+            tag("mul("),
+            separated_pair(unsigned::<u32>, char(','), unsigned),
+            char(')'),
+        );
+        let found = find_all("xmul(2,4)%mul[3,7]!mul(5,5)mul(32,64]", muls);
+        assert_eq!(found, [(2, 4), (5, 5)]);
+    }
+}
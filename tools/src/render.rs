@@ -0,0 +1,214 @@
+use crate::geom::{Direction, Point};
+use crate::grid::{Cell, Grid};
+use crate::sparse::{BoundingBox, SparseGrid};
+use ndarray::Array2;
+use std::collections::HashMap;
+use std::fmt::{Display, Write as _};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+// characters drawn over the base grid
+// positions off the grid are ignored
+#[derive(Clone, Default)]
+pub struct Layer {
+    cells: HashMap<(usize, usize), char>,
+    color: Option<Color>,
+}
+
+impl Layer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn mark(mut self, pos: (usize, usize), ch: char) -> Self {
+        self.cells.insert(pos, ch);
+        self
+    }
+
+    pub fn marks(mut self, positions: impl IntoIterator<Item = (usize, usize)>, ch: char) -> Self {
+        self.cells
+            .extend(positions.into_iter().map(|pos| (pos, ch)));
+        self
+    }
+
+    // each step drawn as an arrow toward the next one, the last as 'o'
+    // steps that aren't to a neighbor are drawn as '*'
+    pub fn path(mut self, path: &[(usize, usize)]) -> Self {
+        for (idx, pos) in path.iter().enumerate() {
+            let ch = match path.get(idx + 1) {
+                Some(next) => step_arrow(*pos, *next),
+                None => 'o',
+            };
+
+            self.cells.insert(*pos, ch);
+        }
+
+        self
+    }
+
+    // text written rightward from pos
+    pub fn label(mut self, pos: (usize, usize), text: &str) -> Self {
+        for (col, ch) in (pos.1..).zip(text.chars()) {
+            self.cells.insert((pos.0, col), ch);
+        }
+
+        self
+    }
+}
+
+fn step_arrow(from: (usize, usize), to: (usize, usize)) -> char {
+    let delta = Point::from(from).vector_to(Point::from(to));
+    Direction::from_delta(delta).map_or('*', Direction::to_arrow)
+}
+
+// a grid with layers drawn over it, later layers on top
+// render with Display (to_string, println) or write_to
+pub struct Render {
+    base: Array2<char>,
+    layers: Vec<Layer>,
+    color: bool,
+}
+
+impl Render {
+    pub fn blank(dim: (usize, usize), ch: char) -> Self {
+        Self::from_chars(Array2::from_elem(dim, ch))
+    }
+
+    pub fn grid<T: Cell>(grid: &Grid<T>) -> Self {
+        Self::array(grid.as_array())
+    }
+
+    pub fn array<T: Cell>(arr: &Array2<T>) -> Self {
+        Self::from_chars(arr.map(Cell::to_char))
+    }
+
+    // the part of a sparse grid inside `bounds`, empty cells drawn as `fill`
+    // layers are positioned relative to bounds.min
+    pub fn sparse<T: Cell>(grid: &SparseGrid<T>, bounds: BoundingBox, fill: char) -> Self {
+        let mut base = Array2::from_elem(bounds.dim(), fill);
+
+        for (pos, cell) in grid.iter() {
+            if let Some(pos) = bounds.to_local(pos) {
+                base[pos] = cell.to_char();
+            }
+        }
+
+        Self::from_chars(base)
+    }
+
+    fn from_chars(base: Array2<char>) -> Self {
+        Self {
+            base,
+            layers: Vec::new(),
+            color: false,
+        }
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    // colour layers with ANSI escapes, off by default
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn write_to(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(w, "{self}")
+    }
+
+    fn cell(&self, pos: (usize, usize)) -> (char, Option<Color>) {
+        self.layers
+            .iter()
+            .rev()
+            .find_map(|layer| Some((*layer.cells.get(&pos)?, layer.color)))
+            .unwrap_or((self.base[pos], None))
+    }
+}
+
+impl Display for Render {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (num_rows, num_cols) = self.base.dim();
+
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                match self.cell((row, col)) {
+                    (ch, Some(color)) if self.color => {
+                        write!(f, "\x1b[{}m{ch}\x1b[0m", color.ansi_code())?
+                    }
+                    (ch, _) => f.write_char(ch)?,
+                }
+            }
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr as _;
+
+    #[test]
+    fn layers() {
+        let grid: Grid<char> = Grid::from_str("#####\n#...#\n#...#\n#####").unwrap();
+        let render = Render::grid(&grid)
+            .layer(Layer::new().path(&[(1, 1), (1, 2), (2, 2), (2, 3)]))
+            .layer(Layer::new().label((0, 1), "S").mark((9, 9), 'X'));
+
+        assert_eq!(render.to_string(), "#S###\n#>v.#\n#.>o#\n#####\n");
+    }
+
+    #[test]
+    fn color() {
+        let layer = Layer::new().mark((0, 1), '*').with_color(Color::Red);
+        let render = Render::blank((1, 3), '.').layer(layer).color(true);
+
+        assert_eq!(render.to_string(), ".\x1b[31m*\x1b[0m.\n");
+    }
+
+    #[test]
+    fn sparse() {
+        let grid: SparseGrid<char> = [((-1, 0), 'a'), ((0, 1), 'a'), ((2, 2), 'b')]
+            .into_iter()
+            .collect();
+        let bounds = BoundingBox {
+            min: (0, 0),
+            max: (2, 3),
+        };
+        let render =
+            Render::sparse(&grid, bounds, '.').layer(Layer::new().marks([(1, 2), (2, 2)], '#'));
+
+        assert_eq!(render.to_string(), ".a..\n..#.\n..#.\n");
+    }
+}
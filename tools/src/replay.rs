@@ -0,0 +1,226 @@
+use crate::grid::{Cell, Grid};
+use crate::render::{Color, Layer, Render};
+use ndarray::Array2;
+
+// receives what a simulation draws as it runs
+// cells set since the last end_frame make up one frame
+pub trait Recorder {
+    fn set(&mut self, pos: (usize, usize), ch: char);
+
+    fn end_frame(&mut self, label: impl FnOnce() -> String);
+
+    // a frame from the whole grid, only cells that changed are kept
+    fn snapshot<T: Cell>(&mut self, grid: &Grid<T>, label: impl FnOnce() -> String) {
+        for (pos, cell) in grid.iter() {
+            self.set(pos, cell.to_char());
+        }
+
+        self.end_frame(label);
+    }
+}
+
+// for runs that aren't being recorded
+pub struct NoRecording;
+
+impl Recorder for NoRecording {
+    fn set(&mut self, _pos: (usize, usize), _ch: char) {}
+
+    fn end_frame(&mut self, _label: impl FnOnce() -> String) {}
+
+    fn snapshot<T: Cell>(&mut self, _grid: &Grid<T>, _label: impl FnOnce() -> String) {}
+}
+
+struct Change {
+    pos: (usize, usize),
+    old: char,
+    new: char,
+}
+
+struct Frame {
+    label: String,
+    changes: Vec<Change>,
+}
+
+// a starting grid and the changes made in each frame after it
+pub struct Recording {
+    start: Array2<char>,
+    frames: Vec<Frame>,
+    // the grid as of the last set, for diffing
+    current: Array2<char>,
+    pending: Vec<Change>,
+}
+
+impl Recording {
+    pub fn new<T: Cell>(grid: &Grid<T>) -> Self {
+        let start = grid.as_array().map(Cell::to_char);
+
+        Self {
+            current: start.clone(),
+            start,
+            frames: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    // frame 0 is the starting grid
+    pub fn num_frames(&self) -> usize {
+        self.frames.len() + 1
+    }
+
+    pub fn replay(&self) -> Replay<'_> {
+        Replay {
+            recording: self,
+            frame_idx: 0,
+            state: self.start.clone(),
+        }
+    }
+
+    // every frame in order, each headed by its number and label
+    pub fn export(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        let mut replay = self.replay();
+
+        loop {
+            writeln!(w, "{}", replay.header())?;
+            replay.render().write_to(w)?;
+
+            if !replay.forward() {
+                break Ok(());
+            }
+        }
+    }
+}
+
+impl Recorder for Recording {
+    fn set(&mut self, pos: (usize, usize), new: char) {
+        let Some(cell) = self.current.get_mut(pos) else {
+            return;
+        };
+
+        if *cell != new {
+            self.pending.push(Change {
+                pos,
+                old: *cell,
+                new,
+            });
+            *cell = new;
+        }
+    }
+
+    fn end_frame(&mut self, label: impl FnOnce() -> String) {
+        self.frames.push(Frame {
+            label: label(),
+            changes: std::mem::take(&mut self.pending),
+        });
+    }
+}
+
+// a cursor over a recording's frames
+pub struct Replay<'a> {
+    recording: &'a Recording,
+    frame_idx: usize,
+    state: Array2<char>,
+}
+
+impl Replay<'_> {
+    pub fn frame_idx(&self) -> usize {
+        self.frame_idx
+    }
+
+    pub fn label(&self) -> &str {
+        match self.frame_idx {
+            0 => "start",
+            idx => &self.recording.frames[idx - 1].label,
+        }
+    }
+
+    pub fn header(&self) -> String {
+        format!(
+            "frame {}/{}: {}",
+            self.frame_idx,
+            self.recording.num_frames() - 1,
+            self.label()
+        )
+    }
+
+    // returns false at the last frame
+    pub fn forward(&mut self) -> bool {
+        let Some(frame) = self.recording.frames.get(self.frame_idx) else {
+            return false;
+        };
+
+        for change in &frame.changes {
+            self.state[change.pos] = change.new;
+        }
+
+        self.frame_idx += 1;
+        true
+    }
+
+    // returns false at the first frame
+    pub fn back(&mut self) -> bool {
+        if self.frame_idx == 0 {
+            return false;
+        }
+
+        self.frame_idx -= 1;
+        let frame = &self.recording.frames[self.frame_idx];
+
+        for change in frame.changes.iter().rev() {
+            self.state[change.pos] = change.old;
+        }
+
+        true
+    }
+
+    // clamps to the last frame
+    pub fn jump(&mut self, frame_idx: usize) {
+        while self.frame_idx > frame_idx && self.back() {}
+        while self.frame_idx < frame_idx && self.forward() {}
+    }
+
+    // the current frame, with the cells it changed highlighted
+    pub fn render(&self) -> Render {
+        let mut changed = Layer::new().with_color(Color::Yellow);
+
+        if let Some(frame) = self.frame_idx.checked_sub(1) {
+            for change in &self.recording.frames[frame].changes {
+                changed = changed.mark(change.pos, change.new);
+            }
+        }
+
+        Render::array(&self.state).layer(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr as _;
+
+    #[test]
+    fn step_and_jump() {
+        let grid: Grid<char> = Grid::from_str("...\n...").unwrap();
+        let mut rec = Recording::new(&grid);
+
+        for col in 0..3 {
+            rec.set((0, col), '#');
+            rec.end_frame(|| format!("col {col}"));
+        }
+
+        let mut replay = rec.replay();
+        assert_eq!(replay.render().to_string(), "...\n...\n");
+
+        replay.jump(2);
+        assert_eq!(replay.label(), "col 1");
+        assert_eq!(replay.render().to_string(), "##.\n...\n");
+
+        assert!(replay.forward());
+        assert!(!replay.forward());
+        assert_eq!(replay.render().to_string(), "###\n...\n");
+
+        assert!(replay.back());
+        replay.jump(0);
+        assert_eq!(replay.render().to_string(), "...\n...\n");
+        assert!(!replay.back());
+    }
+}
@@ -0,0 +1,382 @@
+use crate::heap::IndexedHeap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// a search that reached a goal
+pub struct SearchResult<S> {
+    pub cost: usize,
+    // start to goal, inclusive
+    pub path: Vec<S>,
+    // the state each reached state was first reached from on a best path
+    pub predecessors: HashMap<S, S>,
+}
+
+impl<S: Hash + Eq + Clone> SearchResult<S> {
+    fn new(goal: S, cost: usize, predecessors: HashMap<S, S>) -> Self {
+        let mut path = vec![goal];
+
+        while let Some(prev) = predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+
+        path.reverse();
+
+        Self {
+            cost,
+            path,
+            predecessors,
+        }
+    }
+
+    pub fn goal(&self) -> &S {
+        self.path.last().unwrap()
+    }
+}
+
+// breadth first, every step costs 1
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut frontier = VecDeque::from([(start.clone(), 0)]);
+    let mut seen = HashSet::from([start]);
+    let mut predecessors = HashMap::new();
+
+    while let Some((state, cost)) = frontier.pop_front() {
+        if is_goal(&state) {
+            return Some(SearchResult::new(state, cost, predecessors));
+        }
+
+        for next in neighbors(&state) {
+            if seen.insert(next.clone()) {
+                predecessors.insert(next.clone(), state.clone());
+                frontier.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    None
+}
+
+// uniform cost, neighbors come with the cost of the step to them
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, neighbors, |_state| 0, is_goal)
+}
+
+// dijkstra guided by `heuristic`, which must be consistent: never more than a step's
+// cost plus the estimate from where it leads, and 0 at goals
+// an expanded state is closed for good, so an estimate that's only admissible can miss the best path
+pub fn astar<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S>>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut frontier = IndexedHeap::new();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut expanded = HashSet::new();
+    let mut predecessors = HashMap::new();
+
+    frontier.push(start.clone(), heuristic(&start));
+
+    while let Some((state, _estimate)) = frontier.pop() {
+        let cost = costs[&state];
+
+        if is_goal(&state) {
+            return Some(SearchResult::new(state, cost, predecessors));
+        }
+
+        expanded.insert(state.clone());
+
+        for (next, step_cost) in neighbors(&state) {
+            if expanded.contains(&next) {
+                continue;
+            }
+
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_some_and(|old| *old <= next_cost) {
+                continue;
+            }
+
+            costs.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), state.clone());
+
+            let estimate = next_cost + heuristic(&next);
+            frontier.push_or_decrease(next, estimate);
+        }
+    }
+
+    None
+}
+
+// best costs from one start to every reachable state
+// unlike SearchResult, every predecessor on a best path is kept, so the
+// predecessors form a DAG holding all the best paths
+// that needs every step to cost at least 1, with free steps states could be
+// each other's predecessors
+pub struct DistanceMap<S> {
+    pub start: S,
+    pub costs: HashMap<S, usize>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    // states in the order their cost was settled, predecessors always come first
+    order: Vec<S>,
+}
+
+impl<S: Hash + Eq + Clone> DistanceMap<S> {
+    fn new(start: S) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+            start,
+        }
+    }
+
+    // record that `next` can be reached from `state` for `next_cost`,
+    // returns true if that's a new best cost
+    fn relax(&mut self, state: &S, next: &S, next_cost: usize) -> bool {
+        if *next == self.start {
+            return false;
+        }
+
+        match self.costs.get(next) {
+            Some(old) if *old < next_cost => false,
+            Some(old) if *old == next_cost => {
+                self.predecessors
+                    .entry(next.clone())
+                    .or_default()
+                    .push(state.clone());
+                false
+            }
+            _ => {
+                self.costs.insert(next.clone(), next_cost);
+                self.predecessors.insert(next.clone(), vec![state.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.costs.get(state).copied()
+    }
+
+    // the reachable targets that are cheapest to reach
+    fn best_targets(&self, targets: impl IntoIterator<Item = S>) -> Vec<S> {
+        let mut best = Vec::new();
+        let mut best_cost = usize::MAX;
+
+        for target in targets {
+            let Some(cost) = self.cost(&target) else {
+                continue;
+            };
+
+            if cost < best_cost {
+                best.clear();
+                best_cost = cost;
+            }
+
+            if cost == best_cost {
+                best.push(target);
+            }
+        }
+
+        best
+    }
+
+    // every state on any best path to the cheapest of `targets`
+    pub fn on_best_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut stack = self.best_targets(targets);
+        let mut seen: HashSet<S> = stack.iter().cloned().collect();
+
+        while let Some(state) = stack.pop() {
+            for prev in self.predecessors.get(&state).into_iter().flatten() {
+                if seen.insert(prev.clone()) {
+                    stack.push(prev.clone());
+                }
+            }
+        }
+
+        seen
+    }
+
+    // how many distinct best paths lead to the cheapest of `targets`
+    pub fn count_paths(&self, targets: impl IntoIterator<Item = S>) -> usize {
+        let mut counts: HashMap<&S, usize> = HashMap::from([(&self.start, 1)]);
+
+        for state in &self.order {
+            if let Some(prevs) = self.predecessors.get(state) {
+                let count = prevs.iter().map(|prev| counts[prev]).sum();
+                counts.insert(state, count);
+            }
+        }
+
+        self.best_targets(targets)
+            .iter()
+            .map(|target| counts[target])
+            .sum()
+    }
+
+    // every best path to the cheapest of `targets`, start to target inclusive
+    // there can be exponentially many, check count_paths first
+    pub fn paths(&self, targets: impl IntoIterator<Item = S>) -> Vec<Vec<S>> {
+        let mut paths = Vec::new();
+        // partial paths, built backward from a target
+        let mut stack: Vec<Vec<S>> = self
+            .best_targets(targets)
+            .into_iter()
+            .map(|target| vec![target])
+            .collect();
+
+        while let Some(partial) = stack.pop() {
+            let prevs = self.predecessors.get(partial.last().unwrap());
+
+            match prevs {
+                Some(prevs) => {
+                    for prev in prevs {
+                        let mut longer = partial.clone();
+                        longer.push(prev.clone());
+                        stack.push(longer);
+                    }
+                }
+                None => {
+                    let mut path = partial;
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+
+        paths
+    }
+}
+
+// breadth first from start to everything reachable, every step costs 1
+pub fn bfs_all<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> DistanceMap<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut map = DistanceMap::new(start.clone());
+    let mut frontier = VecDeque::from([(start, 0)]);
+
+    while let Some((state, cost)) = frontier.pop_front() {
+        for next in neighbors(&state) {
+            if map.relax(&state, &next, cost + 1) {
+                frontier.push_back((next, cost + 1));
+            }
+        }
+
+        map.order.push(state);
+    }
+
+    map
+}
+
+// uniform cost from start to everything reachable
+// panics on a step that costs 0, see DistanceMap
+pub fn dijkstra_all<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I) -> DistanceMap<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut map = DistanceMap::new(start.clone());
+    let mut frontier = IndexedHeap::new();
+
+    frontier.push(start, 0);
+
+    // costs are always positive, so a settled state is never improved on
+    while let Some((state, cost)) = frontier.pop() {
+        for (next, step_cost) in neighbors(&state) {
+            assert!(step_cost > 0, "free step in dijkstra_all");
+            if map.relax(&state, &next, cost + step_cost) {
+                frontier.push_or_decrease(next, cost + step_cost);
+            }
+        }
+
+        map.order.push(state);
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a ring of 10 states where stepping forward costs 1 and backward costs 3
+    fn ring(state: &u32) -> [(u32, usize); 2] {
+        [((state + 1) % 10, 1), ((state + 9) % 10, 3)]
+    }
+
+    #[test]
+    fn searches_agree() {
+        let by_bfs = bfs(
+            0,
+            |state| ring(state).map(|(next, _)| next),
+            |state| *state == 7,
+        )
+        .unwrap();
+        assert_eq!(by_bfs.cost, 3);
+        assert_eq!(by_bfs.path, [0, 9, 8, 7]);
+
+        let by_dijkstra = dijkstra(0, ring, |state| *state == 7).unwrap();
+        assert_eq!(by_dijkstra.cost, 7);
+        assert_eq!(by_dijkstra.path, [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(by_dijkstra.predecessors[&3], 2);
+
+        let by_astar = astar(
+            0,
+            ring,
+            |state| (7 - state.min(&7)) as usize,
+            |state| *state == 7,
+        )
+        .unwrap();
+        assert_eq!(by_astar.cost, 7);
+        assert_eq!(*by_astar.goal(), 7);
+
+        assert!(bfs(0, |_state| [], |state| *state == 7).is_none());
+    }
+
+    #[test]
+    fn all_best_paths() {
+        // a diamond, 0 to 3 through either 1 or 2, then on to 4
+        let edges = |state: &u32| match state {
+            0 => vec![(1, 1), (2, 1)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        };
+
+        let map = dijkstra_all(0, edges);
+        assert_eq!(map.cost(&4), Some(3));
+        assert_eq!(map.predecessors[&3].len(), 2);
+        assert_eq!(map.count_paths([4]), 2);
+        assert_eq!(map.on_best_paths([4]).len(), 5);
+        assert_eq!(map.on_best_paths([2, 4]), HashSet::from([0, 2]));
+
+        let mut paths = map.paths([4]);
+        paths.sort();
+        assert_eq!(paths, [[0, 1, 3, 4], [0, 2, 3, 4]]);
+
+        let by_bfs = bfs_all(0, |state| edges(state).into_iter().map(|(next, _)| next));
+        assert_eq!(by_bfs.costs, map.costs);
+        assert_eq!(by_bfs.count_paths([3, 4]), 2);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::grid::{Cell, Grid};
+use crate::parse::{parse_numbered_lines, ParseError};
+use anyhow::{anyhow, Error};
+use std::collections::VecDeque;
+
+// one block of non-blank lines
+pub struct Section<'a> {
+    // 1-based line number of the first line, for error messages
+    first_line: usize,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    pub fn lines(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.lines.iter().copied()
+    }
+
+    // every line parsed by `f`, errors are located at their line
+    pub fn parse_lines<T>(
+        &self,
+        f: impl FnMut(&'a str) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        parse_numbered_lines(self.first_line, self.lines(), f)
+    }
+
+    // the line at `idx` within the section parsed by `f`, for sections with a fixed layout
+    pub fn parse_line<T>(
+        &self,
+        idx: usize,
+        f: impl FnOnce(&'a str) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let Some(ln) = self.lines.get(idx) else {
+            let last_line = self.first_line + self.lines.len() - 1;
+            return Err(ParseError::new(format!("missing line {}", idx + 1))
+                .with_line(last_line, self.lines[self.lines.len() - 1])
+                .into());
+        };
+
+        f(ln).map_err(|err| ParseError::at_line(err, self.first_line + idx, ln))
+    }
+
+    // lines of the form `<key><sep><value>`, each pair parsed by `f`
+    pub fn parse_pairs<T>(
+        &self,
+        sep: &str,
+        mut f: impl FnMut(&'a str, &'a str) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.parse_lines(|ln| {
+            let (key, value) = ln
+                .split_once(sep)
+                .ok_or_else(|| anyhow!("missing {sep:?}"))?;
+            f(key, value)
+        })
+    }
+
+    pub fn parse_grid<T: Cell>(&self) -> Result<Grid<T>, Error> {
+        Grid::from_numbered_lines(self.first_line, self.lines())
+    }
+
+    // the lines run together, for sections that are one long value split over lines
+    pub fn joined(&self) -> String {
+        self.lines.concat()
+    }
+}
+
+// input split into blank-line separated sections, taken in order
+pub struct Sections<'a> {
+    sections: VecDeque<Section<'a>>,
+    num_taken: usize,
+}
+
+impl<'a> Sections<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let mut sections = VecDeque::new();
+        let mut current: Option<Section> = None;
+
+        for (line_num, ln) in (1..).zip(text.lines()) {
+            if ln.trim().is_empty() {
+                sections.extend(current.take());
+                continue;
+            }
+
+            current
+                .get_or_insert_with(|| Section {
+                    first_line: line_num,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(ln);
+        }
+
+        sections.extend(current);
+
+        Self {
+            sections,
+            num_taken: 0,
+        }
+    }
+
+    pub fn next_section(&mut self) -> Result<Section<'a>, Error> {
+        self.num_taken += 1;
+        self.sections
+            .pop_front()
+            .ok_or_else(|| ParseError::new(format!("missing section {}", self.num_taken)).into())
+    }
+
+    pub fn next_lines<T>(
+        &mut self,
+        f: impl FnMut(&'a str) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.next_section()?.parse_lines(f)
+    }
+
+    pub fn next_pairs<T>(
+        &mut self,
+        sep: &str,
+        f: impl FnMut(&'a str, &'a str) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.next_section()?.parse_pairs(sep, f)
+    }
+
+    pub fn next_grid<T: Cell>(&mut self) -> Result<Grid<T>, Error> {
+        self.next_section()?.parse_grid()
+    }
+
+    // every remaining section parsed by `f`, errors without a line are put at its first line
+    pub fn parse_rest<T>(
+        &mut self,
+        mut f: impl FnMut(Section<'a>) -> Result<T, Error>,
+    ) -> Result<Vec<T>, Error> {
+        self.num_taken += self.sections.len();
+        self.sections
+            .drain(..)
+            .map(|section| {
+                let (first_line, first_text) = (section.first_line, section.lines[0]);
+                f(section).map_err(|err| ParseError::at_line(err, first_line, first_text))
+            })
+            .collect()
+    }
+
+    // errors if any sections were left unread
+    pub fn finish(self) -> Result<(), Error> {
+        match self.sections.front() {
+            Some(section) => Err(ParseError::new("unexpected section")
+                .with_line(section.first_line, section.lines[0])
+                .into()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{parse_all, unsigned};
+    use std::str::FromStr as _;
+
+    const TEXT: &str = "\
+x00: 1
+x01: 0
+
+#.
+.#
+
+Button A
+Prize
+
+rest
+";
+
+    #[test]
+    fn sections_in_order() {
+        let mut sections = Sections::new(TEXT);
+
+        let wires = sections
+            .next_pairs(": ", |name, val| Ok((name, u8::from_str(val)?)))
+            .unwrap();
+        assert_eq!(wires, [("x00", 1), ("x01", 0)]);
+
+        let grid: Grid<char> = sections.next_grid().unwrap();
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+
+        let machine = sections.next_section().unwrap();
+        assert_eq!(machine.joined(), "Button APrize");
+        assert_eq!(machine.parse_line(1, |ln| Ok(ln.len())).unwrap(), 5);
+
+        // errors give the line in the whole input
+        let err = machine
+            .parse_line(0, |ln| parse_all(ln, unsigned::<u32>))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7, col 1: unexpected \"Button A\"\n    Button A\n    ^"
+        );
+        let err = machine.parse_line(2, |ln| Ok(ln.len())).unwrap_err();
+        assert_eq!(err.to_string(), "line 8: missing line 3\n    Prize");
+
+        let err = Sections::new(TEXT).finish().unwrap_err();
+        assert_eq!(err.to_string(), "line 1: unexpected section\n    x00: 1");
+
+        let err = sections
+            .parse_rest(|_section| Err::<(), _>(anyhow!("no good")))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 10: no good\n    rest");
+
+        let err = sections.next_section().err().unwrap();
+        assert_eq!(err.to_string(), "missing section 5");
+        sections.finish().unwrap();
+    }
+}
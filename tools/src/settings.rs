@@ -0,0 +1,52 @@
+use anyhow::{anyhow, Error};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// puzzle parameters given in the puzzle text rather than the input, like a grid
+// size or a threshold the examples shrink; a day falls back to the full input's
+// value for any that aren't set
+#[derive(Clone, Debug, Default)]
+pub struct Settings {
+    values: HashMap<String, String>,
+}
+
+impl Settings {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_owned(), value.to_owned());
+    }
+
+    // the setting parsed as a T, or `default` if it isn't set
+    pub fn get_or<T: FromStr>(&self, name: &str, default: T) -> Result<T, Error>
+    where
+        T::Err: Display,
+    {
+        match self.values.get(name) {
+            Some(value) => {
+                T::from_str(value).map_err(|err| anyhow!("setting {name} = {value}: {err}"))
+            }
+            None => Ok(default),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_and_overrides() {
+        let mut settings = Settings::default();
+        settings.set("size", "7");
+        settings.set("name", "seven");
+
+        assert_eq!(settings.get_or("size", 71).unwrap(), 7);
+        assert_eq!(settings.get_or("bytes", 1024).unwrap(), 1024);
+
+        let err = settings.get_or("name", 0usize).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "setting name = seven: invalid digit found in string"
+        );
+    }
+}
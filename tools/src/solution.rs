@@ -0,0 +1,159 @@
+use crate::ledger::Ledger;
+use crate::parse::ParseError;
+use crate::replay::Recording;
+use crate::settings::Settings;
+use anyhow::{bail, Error};
+use std::fmt::Display;
+use std::io::Read as _;
+use std::str::FromStr;
+
+// a day's puzzle: parse the input once, then answer each part from the parsed input
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(text: &str) -> Result<Self::Input, Error>;
+
+    // parse with the input's puzzle settings, for days whose puzzles have any
+    fn parse_with(text: &str, _settings: &Settings) -> Result<Self::Input, Error> {
+        Self::parse(text)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::Answer1, Error>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::Answer2, Error>;
+
+    // a recording of the simulation behind a part, for days that can make one
+    fn record(_input: &Self::Input, _part: u32) -> Result<Recording, Error> {
+        bail!("no recording for this day");
+    }
+}
+
+// which parts to solve
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Part {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl Part {
+    pub fn includes_one(self) -> bool {
+        matches!(self, Part::One | Part::Both)
+    }
+
+    pub fn includes_two(self) -> bool {
+        matches!(self, Part::Two | Part::Both)
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => bail!("invalid part {s:?}, expected 1, 2 or both"),
+        }
+    }
+}
+
+// answers with the day's types erased, so days can be handled uniformly
+// a part that wasn't asked for is None
+pub struct Answers {
+    pub part_one: Option<Result<String, Error>>,
+    pub part_two: Option<Result<String, Error>>,
+}
+
+impl Answers {
+    pub fn print(&self) {
+        self.print_with(|_part, _answer| None);
+    }
+
+    // annotate each answer with what the ledger knows about it
+    pub fn print_checked(&self, ledger: &Ledger, input: &str) {
+        self.print_with(|part, answer| Some(ledger.check(input, part, answer).to_string()));
+    }
+
+    fn print_with(&self, note: impl Fn(u32, &str) -> Option<String>) {
+        for (part, answer) in [(1, &self.part_one), (2, &self.part_two)] {
+            match answer {
+                Some(Ok(answer)) => match note(part, answer) {
+                    Some(note) => println!("part_{part} = {answer} ({note})"),
+                    None => println!("part_{part} = {answer}"),
+                },
+                Some(Err(err)) => println!("part_{part} failed: {err:#}"),
+                None => (),
+            }
+        }
+    }
+}
+
+pub fn solve<S: Solution>(text: &str, settings: &Settings, part: Part) -> Result<Answers, Error> {
+    let input = S::parse_with(text, settings)?;
+
+    let part_one = part
+        .includes_one()
+        .then(|| S::part_one(&input).map(|answer| answer.to_string()));
+
+    let part_two = part
+        .includes_two()
+        .then(|| S::part_two(&input).map(|answer| answer.to_string()));
+
+    Ok(Answers { part_one, part_two })
+}
+
+// a parsed input with the day's types erased, for solving its parts separately,
+// from whichever threads want them
+pub trait Parsed: Send + Sync {
+    fn solve_part(&self, part: u32) -> Result<String, Error>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S>
+where
+    S::Input: Send + Sync,
+{
+    fn solve_part(&self, part: u32) -> Result<String, Error> {
+        match part {
+            1 => S::part_one(&self.0).map(|answer| answer.to_string()),
+            2 => S::part_two(&self.0).map(|answer| answer.to_string()),
+            _ => bail!("invalid part {part}"),
+        }
+    }
+}
+
+pub fn parse_input<S: Solution + 'static>(
+    text: &str,
+    settings: &Settings,
+) -> Result<Box<dyn Parsed>, Error>
+where
+    S::Input: Send + Sync,
+{
+    Ok(Box::new(ParsedInput::<S>(S::parse_with(text, settings)?)))
+}
+
+pub fn record<S: Solution>(text: &str, settings: &Settings, part: u32) -> Result<Recording, Error> {
+    S::record(&S::parse_with(text, settings)?, part)
+}
+
+// entry point for the per-day binaries, which read their input from stdin
+// an optional argument selects the part to solve, stdin is taken to be a full input
+pub fn main<S: Solution>() -> Result<(), Error> {
+    let part = match std::env::args().nth(1) {
+        Some(arg) => Part::from_str(&arg)?,
+        None => Part::Both,
+    };
+
+    let mut text = String::new();
+    std::io::stdin().read_to_string(&mut text)?;
+    solve::<S>(&text, &Settings::default(), part)
+        .map_err(|err| ParseError::in_source(err, "stdin"))?
+        .print();
+    Ok(())
+}
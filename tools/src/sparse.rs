@@ -0,0 +1,234 @@
+use crate::geom::{Direction, Vector};
+use crate::grid::{Cell, Grid};
+use crate::render::Render;
+use crate::stencil::Stencil;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::Index;
+
+// an inclusive rectangle of signed (row, col) positions
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BoundingBox {
+    pub min: (i64, i64),
+    pub max: (i64, i64),
+}
+
+impl BoundingBox {
+    // the positions of a dense grid of size `dim`
+    pub fn of_dim(dim: (usize, usize)) -> Self {
+        Self {
+            min: (0, 0),
+            max: (dim.0 as i64 - 1, dim.1 as i64 - 1),
+        }
+    }
+
+    // (rows, cols)
+    pub fn dim(&self) -> (usize, usize) {
+        let num_rows = (self.max.0 - self.min.0 + 1).max(0);
+        let num_cols = (self.max.1 - self.min.1 + 1).max(0);
+        (num_rows as usize, num_cols as usize)
+    }
+
+    pub fn contains(&self, pos: (i64, i64)) -> bool {
+        (self.min.0..=self.max.0).contains(&pos.0) && (self.min.1..=self.max.1).contains(&pos.1)
+    }
+
+    // pos on a dense grid whose (0, 0) is min, if it's in the box
+    pub fn to_local(&self, pos: (i64, i64)) -> Option<(usize, usize)> {
+        if !self.contains(pos) {
+            return None;
+        }
+
+        Some(((pos.0 - self.min.0) as usize, (pos.1 - self.min.1) as usize))
+    }
+
+    pub fn from_local(&self, pos: (usize, usize)) -> (i64, i64) {
+        (self.min.0 + pos.0 as i64, self.min.1 + pos.1 as i64)
+    }
+
+    fn include(&mut self, pos: (i64, i64)) {
+        self.min = (self.min.0.min(pos.0), self.min.1.min(pos.1));
+        self.max = (self.max.0.max(pos.0), self.max.1.max(pos.1));
+    }
+}
+
+// cells at signed (row, col) positions, with no edges
+// for grids that are mostly empty or grow in any direction
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(i64, i64), T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the dense grid's cells that pass `keep`, at the same positions
+    pub fn from_dense(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let cells = grid
+            .iter()
+            .filter(|(_pos, cell)| keep(cell))
+            .map(|((row, col), cell)| ((row as i64, col as i64), cell.clone()))
+            .collect();
+
+        Self { cells }
+    }
+
+    // the cells inside `bounds`, with everything else `fill`
+    pub fn to_dense(&self, bounds: BoundingBox, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut grid = Grid::from_elem(bounds.dim(), fill);
+
+        for (pos, cell) in self.iter() {
+            if let Some(pos) = bounds.to_local(pos) {
+                grid[pos] = cell.clone();
+            }
+        }
+
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: (i64, i64)) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: (i64, i64)) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    // returns the cell that was there
+    pub fn insert(&mut self, pos: (i64, i64), cell: T) -> Option<T> {
+        self.cells.insert(pos, cell)
+    }
+
+    pub fn remove(&mut self, pos: (i64, i64)) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    // in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    // the smallest box holding every cell, None if there are none
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut positions = self.cells.keys().copied();
+        let first = positions.next()?;
+
+        let mut bounds = BoundingBox {
+            min: first,
+            max: first,
+        };
+        positions.for_each(|pos| bounds.include(pos));
+
+        Some(bounds)
+    }
+
+    pub fn offset(&self, pos: (i64, i64), delta: Vector) -> (i64, i64) {
+        (pos.0 + delta.dr, pos.1 + delta.dc)
+    }
+
+    pub fn step(&self, pos: (i64, i64), dir: Direction) -> (i64, i64) {
+        self.offset(pos, dir.delta())
+    }
+
+    // every one of the stencil's positions around pos, there are no edges to leave
+    pub fn around<'a>(
+        &self,
+        pos: (i64, i64),
+        stencil: &'a Stencil,
+    ) -> impl Iterator<Item = (i64, i64)> + 'a {
+        stencil
+            .offsets()
+            .iter()
+            .map(move |delta| (pos.0 + delta.dr, pos.1 + delta.dc))
+    }
+}
+
+impl<T> Index<(i64, i64)> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (i64, i64)) -> &T {
+        &self.cells[&pos]
+    }
+}
+
+impl<T> FromIterator<((i64, i64), T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = ((i64, i64), T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+// the bounding box, with empty cells drawn as '.'
+impl<T: Cell> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.bounding_box() {
+            Some(bounds) => write!(f, "{}", Render::sparse(self, bounds, '.')),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr as _;
+
+    #[test]
+    fn sparse_and_dense() {
+        let grid: Grid<char> = Grid::from_str("..#\n#..").unwrap();
+        let mut sparse = SparseGrid::from_dense(&grid, |ch| *ch == '#');
+        assert_eq!(sparse.len(), 2);
+
+        sparse.insert((-2, 4), '@');
+        assert_eq!(sparse[(1, 0)], '#');
+        assert_eq!(
+            sparse.bounding_box(),
+            Some(BoundingBox {
+                min: (-2, 0),
+                max: (1, 4)
+            })
+        );
+        assert_eq!(sparse.to_string(), "....@\n.....\n..#..\n#....\n");
+
+        let dense = sparse.to_dense(BoundingBox::of_dim(grid.dim()), '.');
+        assert_eq!(dense, grid);
+
+        let around: Vec<_> = sparse.around((0, 0), &Stencil::von_neumann()).collect();
+        assert_eq!(around, [(-1, 0), (0, 1), (1, 0), (0, -1)]);
+        assert_eq!(sparse.step((0, 0), Direction::Left), (0, -1));
+
+        let bounds = BoundingBox::of_dim((2, 3));
+        assert_eq!(bounds.to_local((1, 2)), Some((1, 2)));
+        assert_eq!(bounds.to_local((-1, 2)), None);
+        assert_eq!(BoundingBox::of_dim((0, 0)).dim(), (0, 0));
+    }
+}
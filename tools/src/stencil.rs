@@ -0,0 +1,183 @@
+use crate::geom::{Direction, Topology, Vector};
+use anyhow::{bail, Error};
+
+// a fixed set of offsets around a position, e.g. its neighbours
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Stencil {
+    offsets: Vec<Vector>,
+}
+
+impl Stencil {
+    pub fn from_offsets(offsets: impl IntoIterator<Item = Vector>) -> Self {
+        Self {
+            offsets: offsets.into_iter().collect(),
+        }
+    }
+
+    // the four orthogonal neighbours, clockwise from up
+    pub fn von_neumann() -> Self {
+        Self::from_offsets(Direction::ALL.map(Direction::delta))
+    }
+
+    // the four diagonal neighbours, clockwise from up-left
+    pub fn diagonals() -> Self {
+        Self::from_offsets(Direction::ALL.map(|dir| dir.delta() + dir.turn_left().delta()))
+    }
+
+    // all eight neighbours, clockwise from up-left
+    pub fn moore() -> Self {
+        let orthogonal = Self::von_neumann().offsets;
+        let diagonal = Self::diagonals().offsets;
+        Self::from_offsets(
+            diagonal
+                .into_iter()
+                .zip(orthogonal)
+                .flat_map(|(d, o)| [d, o]),
+        )
+    }
+
+    pub fn knight() -> Self {
+        Self::from_offsets(Direction::ALL.into_iter().flat_map(|dir| {
+            let two = dir.delta() * 2;
+            [
+                two + dir.turn_left().delta(),
+                two + dir.turn_right().delta(),
+            ]
+        }))
+    }
+
+    // everything within `radius` steps, not counting the position itself
+    pub fn manhattan(radius: usize) -> Self {
+        let radius = radius as i64;
+
+        Self::from_offsets((-radius..=radius).flat_map(|dr| {
+            let max_dc = radius - dr.abs();
+            (-max_dc..=max_dc)
+                .map(move |dc| Vector::new(dr, dc))
+                .filter(|delta| *delta != Vector::default())
+        }))
+    }
+
+    // offsets drawn as text, `center` marks the position itself and `mark`
+    // each offset, anything else is ignored
+    //   "#.#\n.A.\n#.#" with 'A' and '#' gives the diagonals, in row-major order
+    pub fn from_mask(mask: &str, center: char, mark: char) -> Result<Self, Error> {
+        let cells: Vec<((i64, i64), char)> = (0..)
+            .zip(mask.lines())
+            .flat_map(|(row, ln)| (0..).zip(ln.chars()).map(move |(col, ch)| ((row, col), ch)))
+            .collect();
+
+        let centers: Vec<_> = cells.iter().filter(|(_pos, ch)| *ch == center).collect();
+        let [((center_row, center_col), _)] = centers[..] else {
+            bail!("mask needs exactly one {center:?}, found {}", centers.len());
+        };
+
+        Ok(Self::from_offsets(
+            cells
+                .iter()
+                .filter(|(_pos, ch)| *ch == mark)
+                .map(|((row, col), _ch)| Vector::new(row - center_row, col - center_col)),
+        ))
+    }
+
+    pub fn offsets(&self) -> &[Vector] {
+        &self.offsets
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    // each offset from pos, None where it's off a grid of size `dim`
+    pub fn cells(
+        &self,
+        pos: (usize, usize),
+        dim: (usize, usize),
+    ) -> impl Iterator<Item = Option<(usize, usize)>> + '_ {
+        self.cells_in(pos, dim, Topology::Bounded)
+    }
+
+    // the offsets from pos that are on a grid of size `dim`, in stencil order
+    pub fn around(
+        &self,
+        pos: (usize, usize),
+        dim: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.around_in(pos, dim, Topology::Bounded)
+    }
+
+    // cells() on a grid with the given topology
+    pub fn cells_in(
+        &self,
+        pos: (usize, usize),
+        dim: (usize, usize),
+        topology: Topology,
+    ) -> impl Iterator<Item = Option<(usize, usize)>> + '_ {
+        self.offsets.iter().map(move |delta| {
+            let pos = topology.offset(pos.into(), *delta, dim)?;
+            Some(pos.into())
+        })
+    }
+
+    // around() on a grid with the given topology
+    pub fn around_in(
+        &self,
+        pos: (usize, usize),
+        dim: (usize, usize),
+        topology: Topology,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.cells_in(pos, dim, topology).flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn offset_set(stencil: &Stencil) -> HashSet<Vector> {
+        stencil.offsets().iter().copied().collect()
+    }
+
+    #[test]
+    fn named_sets() {
+        assert_eq!(Stencil::von_neumann().len(), 4);
+        assert_eq!(Stencil::moore().len(), 8);
+        assert_eq!(Stencil::knight().len(), 8);
+        assert_eq!(
+            offset_set(&Stencil::manhattan(1)),
+            offset_set(&Stencil::von_neumann())
+        );
+        assert_eq!(Stencil::manhattan(2).len(), 12);
+
+        let mask = Stencil::from_mask("#.#\n.A.\n#.#", 'A', '#').unwrap();
+        assert_eq!(offset_set(&mask), offset_set(&Stencil::diagonals()));
+        assert!(Stencil::from_mask("#.#", 'A', '#').is_err());
+
+        let mask = Stencil::from_mask("###\n#@#\n###", '@', '#').unwrap();
+        assert_eq!(offset_set(&mask), offset_set(&Stencil::moore()));
+
+        assert!(Stencil::knight()
+            .offsets()
+            .iter()
+            .all(|delta| delta.manhattan() == 3 && delta.dr != 0 && delta.dc != 0));
+    }
+
+    #[test]
+    fn in_bounds() {
+        let corner: Vec<_> = Stencil::moore().around((0, 0), (3, 3)).collect();
+        assert_eq!(corner, [(0, 1), (1, 1), (1, 0)]);
+
+        let cells: Vec<_> = Stencil::diagonals().cells((0, 1), (3, 3)).collect();
+        assert_eq!(cells, [None, None, Some((1, 2)), Some((1, 0))]);
+
+        let wrapped: Vec<_> = Stencil::von_neumann()
+            .around_in((0, 0), (3, 3), Topology::Torus)
+            .collect();
+        assert_eq!(wrapped, [(2, 0), (0, 1), (1, 0), (0, 2)]);
+    }
+}